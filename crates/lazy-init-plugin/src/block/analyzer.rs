@@ -6,6 +6,7 @@ use visitor_state_macro::save_state;
 
 use super::*;
 use context::Context;
+use diagnostics::DiagnosticCode;
 use flow_analyzer::{analyze_flow, TargetNode};
 use stmt_context::{Flags, StmtCtx, StmtType};
 
@@ -15,10 +16,68 @@ pub struct AnalyzeOk {
    pub transform_type: TransformType,
    /// If analysis finished early, this is not the top level context.
    pub final_ctx: StmtCtx,
+   /// The spans of the statements that can never execute, which have been
+   /// removed from the block.
+   pub unreachable: Vec<Span>,
+   /// Why the block cannot be inlined, only set when the transformation type
+   /// is [`TransformType::Wrapped`].
+   pub wrapped_reason: Option<WrappedReason>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WrappedReason {
+   pub kind: WrappedReasonKind,
+   /// The span of the statement that caused the block to be wrapped.
+   pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WrappedReasonKind {
+   /// A statement returns on some paths but can complete without returning.
+   FallsThrough,
+   /// A `break` or `continue` exits the statement, which also returns.
+   /// ```ts
+   /// for (const item of items) {
+   ///    if (item) return item;
+   ///    break;
+   /// }
+   /// ```
+   JumpFallsThrough,
+   /// The block returns, but not on every path.
+   MissingReturn,
+   /// A labeled statement that disrupts the flow.
+   Labeled,
+   /// A `do-while` loop that disrupts the flow.
+   DoWhile,
+}
+
+impl WrappedReasonKind {
+   pub fn message(self) -> &'static str {
+      return match self {
+         WrappedReasonKind::FallsThrough => {
+            "This statement returns on some paths but can complete without \
+             returning."
+         }
+         WrappedReasonKind::JumpFallsThrough => {
+            "A `break` or `continue` can exit this statement after it may have \
+             returned."
+         }
+         WrappedReasonKind::MissingReturn => {
+            "Not every path of the block returns a value."
+         }
+         WrappedReasonKind::Labeled => {
+            "Labeled statements that disrupt the flow cannot be inlined."
+         }
+         WrappedReasonKind::DoWhile => {
+            "`do-while` loops that disrupt the flow cannot be inlined."
+         }
+      };
+   }
 }
 
 #[derive(Debug)]
 pub struct AnalyzeError {
+   pub code: DiagnosticCode,
    pub msg: String,
    pub span: Option<Span>,
 }
@@ -39,6 +98,14 @@ type AnalyzeResult = Result<AnalyzeOk, AnalyzeError>;
 /// Current limitations:
 /// * If a [`DoWhileStmt`] or [`LabeledStmt`] that disrupts the flow is
 ///   encountered, the block is wrapped.
+/// * The reason a block is wrapped is stored in [`AnalyzeOk::wrapped_reason`].
+/// * `await`, `yield` and `yield*` expressions are suspension points that
+///   always resume at the same location, or throw like any other expression.
+///   They never disrupt the flow of the block and are not analyzed.
+///
+/// Statements after a statement that always exits the block can never
+/// execute. They are removed from the block and reported in
+/// [`AnalyzeOk::unreachable`].
 pub fn analyze(block_stmt: &mut Stmt) -> AnalyzeResult {
    debug_assert!(
       matches!(block_stmt, Stmt::Block(_)),
@@ -52,7 +119,7 @@ pub fn analyze(block_stmt: &mut Stmt) -> AnalyzeResult {
    return analyzer.result.expect("Result should exist.");
 }
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum TransformType {
    /// The block contains statements that can be inlined.
    #[default]
@@ -72,6 +139,9 @@ struct Analyzer {
    /// Required for when the `then` branch in an [`IfStmt`] has been visited
    /// but we have transformed the statement by adding an `else` branch.
    next_if_then_stmts: Option<Vec<StmtCtx>>,
+   /// The spans of the unreachable statements that have been removed.
+   unreachable: Vec<Span>,
+   wrapped_reason: Option<WrappedReason>,
    depth: usize,
    finished: bool,
    is_top_level: bool,
@@ -132,7 +202,10 @@ impl Analyzer {
 
       if result.falls_through() {
          self.stmt_mut().add(Flags::FALLS_THROUGH);
-         self.finish_early();
+         self.finish_early(
+            WrappedReasonKind::JumpFallsThrough,
+            self.stmt().span,
+         );
          return true;
       }
 
@@ -144,7 +217,8 @@ impl Analyzer {
       self.stmt_mut().compute();
 
       if self.stmt().has(Flags::FALLS_THROUGH) {
-         return self.finish_early();
+         return self
+            .finish_early(WrappedReasonKind::FallsThrough, self.stmt().span);
       }
       if self.stmt().kind == StmtType::TopLevel {
          // Top level block must always return.
@@ -154,35 +228,62 @@ impl Analyzer {
          if self.stmt().has(Flags::RETURNS) &&
             !self.stmt().has(Flags::ALWAYS_RETURNS)
          {
-            return self.finish_early();
+            return self.finish_early(
+               WrappedReasonKind::MissingReturn,
+               self.stmt().span,
+            );
          }
       }
    }
 
    /// Finish early if a fall through is detected. This is still considered a
    /// success but the transformation type will be [`TransformType::Wrapped`].
-   fn finish_early(&mut self) {
-      debug!("Finishing early.");
+   fn finish_early(&mut self, kind: WrappedReasonKind, span: Span) {
+      debug!("Finishing early: {:?}", kind);
+      self.wrapped_reason = Some(WrappedReason {
+         kind,
+         span,
+      });
       self.success(TransformType::Wrapped);
-      self.finished = true;
    }
 
-   fn visit<T: VisitMutWith<Analyzer>>(
+   fn visit<T: VisitMutWith<Analyzer> + Spanned>(
       &mut self,
       stmt: &mut T,
       kind: Option<StmtType>,
    ) {
+      self.enter(kind, stmt.span());
+      stmt.visit_mut_children_with(self);
+      self.exit();
+   }
+
+   /// Visits the statements of a branch that is not a [`BlockStmt`], e.g. a
+   /// switch case, as a block so unreachable statements are removed.
+   fn visit_stmts(
+      &mut self,
+      stmts: &mut Vec<Stmt>,
+      kind: StmtType,
+      span: Span,
+   ) {
+      self.enter(Some(kind), span);
+      stmts.visit_mut_with(self);
+      self.exit();
+   }
+
+   fn enter(&mut self, kind: Option<StmtType>, span: Span) {
       // Enter new context.
       self.ctx.enter();
       self.depth += 1;
+      self.stmt_mut().span = span;
 
       // Set the kind if it was provided by the parent.
       if let Some(kind) = kind {
          self.set_kind(kind);
       }
+   }
 
+   fn exit(&mut self) {
       // Analyze the statement.
-      stmt.visit_mut_children_with(self);
       self.analyze_stmt();
       bail_if!(self.finished);
 
@@ -196,13 +297,40 @@ impl Analyzer {
       self.result.get_or_insert(Ok(AnalyzeOk {
          transform_type,
          final_ctx: self.ctx.exit(),
+         unreachable: std::mem::take(&mut self.unreachable),
+         wrapped_reason: self.wrapped_reason.take(),
       }));
       self.finished = true;
    }
 
    fn failure(&mut self, error: AnalyzeError) {
       self.result.get_or_insert(Err(error));
-      self.finish_early();
+      self.finished = true;
+   }
+
+   /// Removes the statements from `start` onwards, which can never execute.
+   /// Function declarations are hoisted and type declarations have no effect,
+   /// so both are kept.
+   fn remove_unreachable(&mut self, stmts: &mut Vec<Stmt>, start: usize) {
+      let mut span: Option<Span> = None;
+
+      for stmt in stmts.drain(start..).collect::<Vec<_>>() {
+         match stmt {
+            Stmt::Decl(
+               Decl::Fn(_) | Decl::TsInterface(_) | Decl::TsTypeAlias(_),
+            ) => stmts.push(stmt),
+            Stmt::Empty(_) => {}
+            _ => {
+               span =
+                  Some(span.map_or(stmt.span(), |s| return s.to(stmt.span())));
+            }
+         }
+      }
+
+      if let Some(span) = span {
+         debug!("Removing unreachable statements.");
+         self.unreachable.push(span);
+      }
    }
 }
 
@@ -216,17 +344,26 @@ impl VisitMut for Analyzer {
          bail_if!(self.finished || current_idx >= stmts.len());
 
          let mut found_lonely_if = false;
+         let mut found_exit = false;
          let last_idx = stmts.len() - 1;
 
-         for (idx, stmt) in stmts[current_idx..].iter_mut().enumerate() {
+         for (idx, stmt) in stmts.iter_mut().enumerate().skip(current_idx) {
             current_idx = idx;
             stmt.visit_mut_with(self);
             bail_if!(self.finished);
 
-            // Find the first `if` with no `else` branch. If it's the last
-            // statement there's no need for an `else` branch.
+            // Find the first statement that always exits, or the first `if`
+            // with no `else` branch. If it's the last statement there's no
+            // need to remove the following statements or add an `else`
+            // branch.
             if current_idx != last_idx {
                if let Some(latest) = self.get_mut_children().last() {
+                  // The remaining statements are unreachable.
+                  if latest.always_exits() {
+                     found_exit = true;
+                     break;
+                  }
+
                   // TODO: check this assumption is correct in try-catch.
                   // Specify `RETURNS` since it's unnecessary if it always
                   // returns due to a throw.
@@ -243,6 +380,9 @@ impl VisitMut for Analyzer {
             }
          }
 
+         if found_exit {
+            return self.remove_unreachable(stmts, current_idx + 1);
+         }
          bail_if!(!found_lonely_if);
 
          // Remove the latest child which is expected to be an `if`.
@@ -296,6 +436,7 @@ impl VisitMut for Analyzer {
          stmt.span(),
          ("Top level statement is not a block.\n{:#?}", stmt)
       );
+      self.stmt_mut().span = top_level_block.span;
       top_level_block.visit_mut_children_with(self);
 
       // Analyze the top level block.
@@ -304,6 +445,7 @@ impl VisitMut for Analyzer {
 
       if !self.stmt().has(Flags::RETURNS) {
          return self.failure(AnalyzeError {
+            code: DiagnosticCode::BlockMissingReturn,
             msg: "A block must always contain a `return` statement.".to_owned(),
             span: Some(stmt.span()),
          });
@@ -318,6 +460,7 @@ impl VisitMut for Analyzer {
          return self.success(TransformType::Inline);
       }
       return self.failure(AnalyzeError {
+         code: DiagnosticCode::BlockFinalStmtNoReturn,
          msg: "The final statement in a block must always return a value."
             .to_owned(),
          span: Some(stmt.span()),
@@ -326,6 +469,17 @@ impl VisitMut for Analyzer {
 
    fn visit_mut_block_stmt(&mut self, block_stmt: &mut BlockStmt) {
       self.set_kind(StmtType::Block);
+
+      // An `else` branch created for a lonely `if` has no span, since its
+      // leading comments would otherwise be placed before the `{`. It spans
+      // its statements instead.
+      if let (Some(first), Some(last)) =
+         (block_stmt.stmts.first(), block_stmt.stmts.last())
+      {
+         if block_stmt.span.is_dummy() {
+            self.stmt_mut().span = first.span().to(last.span());
+         }
+      }
       block_stmt.visit_mut_children_with(self);
    }
 
@@ -360,37 +514,22 @@ impl VisitMut for Analyzer {
       self.set_kind(StmtType::Switch);
       bail_if!(self.fast_path(switch_stmt.into()));
 
-      let default_case_idx = switch_stmt
-         .cases
-         .iter()
-         .position(|case| return case.test.is_none());
-
-      if let Some(default_case_idx) = default_case_idx {
-         // Default case must be the last case.
-         swc_assert!(
-            default_case_idx == (switch_stmt.cases.len() - 1),
-            switch_stmt.span,
-            ("The default case must come last in a `switch` statement within \
-              a `lz.block`.")
-         );
-      }
-
-      // Must manually `self.visit()` here as `visit_stmt` will not be called
-      // since a `SwitchCase` is not an actual `Stmt`.
+      // Must manually `self.visit_stmts()` here as `visit_stmt` will not be
+      // called since a `SwitchCase` is not an actual `Stmt`.
+      // Empty cases are also visited, since the order of the cases is needed
+      // to determine where each case falls through to.
       for case in &mut switch_stmt.cases {
          bail_if!(self.finished);
 
-         // Skip empty case statements.
-         if !case.cons.is_empty() {
-            self.visit(
-               &mut case.cons,
-               Some(if case.test.is_some() {
-                  StmtType::SwitchCase
-               } else {
-                  StmtType::SwitchDefault
-               }),
-            );
-         }
+         self.visit_stmts(
+            &mut case.cons,
+            if case.test.is_some() {
+               StmtType::SwitchCase
+            } else {
+               StmtType::SwitchDefault
+            },
+            case.span,
+         );
       }
    }
 
@@ -408,10 +547,11 @@ impl VisitMut for Analyzer {
       // Currently, we do not attempt to inline labeled statements that disrupt
       // the flow. They're seldom used anyway and aren't a priority for
       // the time being.
-      // Note this does not impact nested `lz.block` calls which will be
-      // transformed into labeled statements, since blocks are transformed
-      // top-down.
-      return self.finish_early();
+      // Note this does not impact nested `lz.block` calls, which are
+      // transformed before the enclosing block is analyzed. A nested block
+      // wrapped in a labeled statement only breaks to its own label, so it
+      // has no flow impact.
+      return self.finish_early(WrappedReasonKind::Labeled, self.stmt().span);
    }
 
    fn visit_mut_try_stmt(&mut self, try_stmt: &mut TryStmt) {
@@ -421,17 +561,19 @@ impl VisitMut for Analyzer {
       // Visit the `try` branch.
       let prev_inside_try_block = self.inside_try_block;
       self.inside_try_block = true;
-      self.visit(
+      self.visit_stmts(
          &mut try_stmt.block.stmts,
-         Some(StmtType::TryBlock),
+         StmtType::TryBlock,
+         try_stmt.block.span,
       );
       self.inside_try_block = prev_inside_try_block;
 
       // Visit the `catch` branch.
       if let Some(catch_stmt) = &mut try_stmt.handler {
-         self.visit(
+         self.visit_stmts(
             &mut catch_stmt.body.stmts,
-            Some(StmtType::TryCatch),
+            StmtType::TryCatch,
+            catch_stmt.span,
          );
       }
 
@@ -439,9 +581,10 @@ impl VisitMut for Analyzer {
       if let Some(finally_stmt) = &mut try_stmt.finalizer {
          // self.set_next_kind(StmtType::TryFinally);
          // finally.visit_mut_with(self);
-         self.visit(
+         self.visit_stmts(
             &mut finally_stmt.stmts,
-            Some(StmtType::TryFinally),
+            StmtType::TryFinally,
+            finally_stmt.span,
          );
       }
    }
@@ -453,7 +596,7 @@ impl VisitMut for Analyzer {
       bail_if!(self.fast_path(do_while_stmt.into()));
 
       // TODO: implement inlining `DoWhileStmt`.
-      self.finish_early();
+      self.finish_early(WrappedReasonKind::DoWhile, self.stmt().span);
 
       // let maybe_body;
       // let body = match &*do_while_stmt.body {
@@ -0,0 +1,292 @@
+use swc_common::{comments::Comments, util::take::Take, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::ExprFactory;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+use super::*;
+
+/// Compresses the statements of a transformed block that assign the value of
+/// the block to the temp variable. Only the shapes produced by the inline
+/// transform are compressed, any other statement is left as is.
+///
+/// ### Arguments
+/// * `temp_var` - The temp variable that is assigned the value of the block.
+/// * `comments` - The comments of the removed statements are moved to the
+///   compressed statement.
+///
+/// ### Notes
+/// An `if` statement where both branches assign the temp variable becomes a
+/// conditional expression:
+/// ```js
+/// if (a) { lv = 0; } else if (b) { lv = 1; } else { lv = 2; }
+/// // is compressed to:
+/// lv = a ? 0 : b ? 1 : 2;
+/// ```
+///
+/// A `switch` statement over an identifier, where each case assigns a
+/// constant to the temp variable, becomes a conditional expression. The keys
+/// are matched using strict equality, like a `switch` statement, and nothing
+/// is allocated:
+/// ```js
+/// switch (key) {
+///    case "a": lv = 0; break;
+///    case "b":
+///    case "c": lv = 1; break;
+///    default: lv = 2;
+/// }
+/// // is compressed to:
+/// lv = key === "a" ? 0 : key === "b" || key === "c" ? 1 : 2;
+/// ```
+pub fn compress(
+   stmts: &mut Vec<Stmt>,
+   temp_var: &Ident,
+   comments: Option<&dyn Comments>,
+) {
+   let mut v = Compressor {
+      temp_var: temp_var.to_id(),
+      comments,
+   };
+   stmts.visit_mut_with(&mut v);
+}
+
+struct Compressor<'a> {
+   temp_var: Id,
+   comments: Option<&'a dyn Comments>,
+}
+
+impl Compressor<'_> {
+   /// Returns the value assigned to the temp variable if the statement is
+   /// solely an assignment to it, e.g. `lv = <value>;` or `{ lv = <value>; }`.
+   fn assigned_value<'a>(
+      &self,
+      stmt: &'a mut Stmt,
+   ) -> Option<&'a mut Box<Expr>> {
+      return match stmt {
+         Stmt::Block(BlockStmt {
+            stmts,
+            ..
+         }) if stmts.len() == 1 => self.assigned_value(&mut stmts[0]),
+         Stmt::Expr(ExprStmt {
+            expr,
+            ..
+         }) => match &mut **expr {
+            Expr::Assign(AssignExpr {
+               op: op!("="),
+               left,
+               right,
+               ..
+            }) if left
+               .as_ident()
+               .is_some_and(|i| return i.to_id() == self.temp_var) =>
+            {
+               Some(right)
+            }
+            _ => None,
+         },
+         _ => None,
+      };
+   }
+
+   fn assign(&self, span: Span, value: Expr) -> Stmt {
+      let temp_var = Ident::from(self.temp_var.clone());
+
+      return Stmt::Expr(ExprStmt {
+         span,
+         expr: Box::new(
+            value.make_assign_to(op!("="), temp_var.as_pat_or_expr()),
+         ),
+      });
+   }
+
+   /// `if (a) { lv = 0; } else { lv = 1; }` => `lv = a ? 0 : 1;`
+   fn compress_if(&self, stmt: &mut Stmt) {
+      let if_stmt = unwrap_or!(stmt.as_mut_if_stmt(), return);
+      let alt = unwrap_or!(if_stmt.alt.as_mut(), return);
+
+      // An `else if` branch has already been compressed, since the statements
+      // are visited bottom-up.
+      bail_if!(self.assigned_value(&mut if_stmt.cons).is_none());
+      let alt = unwrap_or!(self.assigned_value(alt), return).take();
+      let cons =
+         unwrap_or!(self.assigned_value(&mut if_stmt.cons), return).take();
+
+      utils::relocate_comments(self.comments, &if_stmt.cons, if_stmt.span);
+      utils::relocate_comments(self.comments, &if_stmt.alt, if_stmt.span);
+
+      let conditional = Expr::Cond(CondExpr {
+         span: if_stmt.span,
+         test: if_stmt.test.take(),
+         cons,
+         alt,
+      });
+      *stmt = self.assign(if_stmt.span, conditional);
+   }
+
+   /// Replaces a `switch` statement assigning a constant in each case with a
+   /// conditional expression. See [`compress`].
+   fn compress_switch(&self, stmt: &mut Stmt) {
+      let switch_stmt = unwrap_or!(stmt.as_mut_switch(), return);
+      // The discriminant is evaluated for each key instead of once, so it is
+      // only compressed when that has no side effects.
+      let discriminant =
+         unwrap_or!(switch_stmt.discriminant.as_ident(), return).clone();
+      let values = unwrap_or!(self.case_values(switch_stmt), return);
+
+      // The keys of each value, in the order the values are first assigned.
+      let mut entries: Vec<(Vec<Lit>, Lit)> = Vec::new();
+      let mut default = None;
+
+      for (case, value) in switch_stmt.cases.iter().zip(values) {
+         if let Some(Expr::Lit(key)) = case.test.as_deref() {
+            // Only the first matching case is used by a `switch` statement.
+            let is_duplicate = entries.iter().any(|(keys, _)| {
+               return keys.iter().any(|k| return is_same_key(k, key));
+            });
+            if is_duplicate {
+               continue;
+            }
+
+            match entries
+               .iter_mut()
+               .find(|(_, v)| return is_same_key(v, &value))
+            {
+               Some((keys, _)) => keys.push(key.clone()),
+               None => entries.push((vec![key.clone()], value)),
+            }
+         } else {
+            // The `default` case.
+            default = Some(value);
+         }
+      }
+      // Without a default the temp variable would be assigned when no key
+      // matches.
+      bail_if!(entries.is_empty());
+      let default = unwrap_or!(default, return);
+
+      // `key === "a" ? 0 : key === "b" || key === "c" ? 1 : 2`
+      let conditional = entries.into_iter().rev().fold(
+         Expr::Lit(default),
+         |alt, (keys, value)| {
+            let test = keys
+               .into_iter()
+               .map(|key| {
+                  return Expr::Bin(BinExpr {
+                     span: DUMMY_SP,
+                     op: op!("==="),
+                     left: Box::new(Expr::Ident(discriminant.clone())),
+                     right: Box::new(Expr::Lit(key)),
+                  });
+               })
+               .reduce(|left, right| {
+                  return Expr::Bin(BinExpr {
+                     span: DUMMY_SP,
+                     op: op!("||"),
+                     left: Box::new(left),
+                     right: Box::new(right),
+                  });
+               });
+
+            return Expr::Cond(CondExpr {
+               span: switch_stmt.span,
+               test: Box::new(test.expect("Each value has a key.")),
+               cons: Box::new(Expr::Lit(value)),
+               alt: Box::new(alt),
+            });
+         },
+      );
+      utils::relocate_comments(
+         self.comments,
+         &switch_stmt.cases,
+         switch_stmt.span,
+      );
+      *stmt = self.assign(switch_stmt.span, conditional);
+   }
+
+   /// Returns the constant assigned by each case, in the same order as the
+   /// cases. An empty case falls through to the next case and uses its value.
+   ///
+   /// Returns `None` if any case does not assign a constant before exiting
+   /// the `switch` statement, or any key is not a constant.
+   fn case_values(&self, switch_stmt: &mut SwitchStmt) -> Option<Vec<Lit>> {
+      let mut values: Vec<Lit> = Vec::new();
+      let mut next_value: Option<Lit> = None;
+      let last_idx = switch_stmt.cases.len().checked_sub(1)?;
+
+      for (idx, case) in switch_stmt.cases.iter_mut().enumerate().rev() {
+         if let Some(test) = case.test.as_deref() {
+            bail_if!(
+               !matches!(test, Expr::Lit(key) if is_constant(key)),
+               None
+            );
+         }
+
+         let mut cons = case.cons.as_mut_slice();
+
+         if let [Stmt::Block(BlockStmt {
+            stmts,
+            ..
+         })] = cons
+         {
+            cons = stmts.as_mut_slice();
+         }
+
+         let value = match cons {
+            [] => next_value.clone()?,
+            [assign, Stmt::Break(BreakStmt {
+               label: None,
+               ..
+            })] => self.constant_value(assign)?,
+            // The last case does not require a `break`.
+            [assign] if idx == last_idx => self.constant_value(assign)?,
+            _ => return None,
+         };
+         values.push(value.clone());
+         next_value = Some(value);
+      }
+
+      values.reverse();
+      return Some(values);
+   }
+
+   fn constant_value(&self, stmt: &mut Stmt) -> Option<Lit> {
+      return match &**self.assigned_value(stmt)? {
+         Expr::Lit(value) if is_constant(value) => Some(value.clone()),
+         _ => None,
+      };
+   }
+}
+
+impl VisitMut for Compressor<'_> {
+   noop_visit_mut_type!();
+
+   fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+      // Visit bottom-up so nested statements are compressed first.
+      stmt.visit_mut_children_with(self);
+
+      match stmt {
+         Stmt::If(_) => self.compress_if(stmt),
+         Stmt::Switch(_) => self.compress_switch(stmt),
+         _ => {}
+      }
+   }
+}
+
+fn is_constant(lit: &Lit) -> bool {
+   return matches!(
+      lit,
+      Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::BigInt(_) | Lit::Null(_)
+   );
+}
+
+/// Whether two keys are strictly equal.
+fn is_same_key(a: &Lit, b: &Lit) -> bool {
+   return match (a, b) {
+      (Lit::Str(a), Lit::Str(b)) => a.value == b.value,
+      #[allow(clippy::float_cmp)]
+      (Lit::Num(a), Lit::Num(b)) => a.value == b.value,
+      (Lit::Bool(a), Lit::Bool(b)) => a.value == b.value,
+      (Lit::BigInt(a), Lit::BigInt(b)) => a.value == b.value,
+      (Lit::Null(_), Lit::Null(_)) => true,
+      _ => false,
+   };
+}
@@ -0,0 +1,109 @@
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use super::*;
+
+/// Each level of nesting is weighted more than a statement, since deeply
+/// nested code is both larger and harder to read.
+const DEPTH_WEIGHT: usize = 4;
+
+/// An estimate of the size and complexity of a transformed block. Used to
+/// choose between the inline and wrapped transforms when the block can be
+/// inlined.
+///
+/// Inlining a block whose statements follow an `if` with no `else` branch
+/// requires moving the statements into an `else` branch. For a sequence of
+/// guard clauses, the output becomes deeply nested:
+/// ```js
+/// if (a) { lv = 0; } else { if (b) { lv = 1; } else { if (c) { ... } } }
+/// // while the wrapped transform remains flat:
+/// Block: { if (a) { lv = 0; break Block; } if (b) { lv = 1; break Block; } ... }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Cost {
+   /// The number of statements, excluding block statements.
+   pub stmts: usize,
+   /// The deepest level of nesting of the statements.
+   pub depth: usize,
+   /// The number of `break` and labeled statements.
+   pub jumps: usize,
+}
+
+impl Cost {
+   /// Estimates the cost of the statements of a transformed block.
+   pub fn of(stmts: &[Stmt]) -> Self {
+      let mut v = Visitor::default();
+      stmts.visit_with(&mut v);
+
+      return v.cost;
+   }
+
+   pub fn score(&self) -> usize {
+      return self.stmts + self.jumps + self.depth * DEPTH_WEIGHT;
+   }
+}
+
+/// Whether the inline transform may nest the statements of the block within
+/// an `else` branch, which is the only case where it can cost more than the
+/// wrapped transform. This is the case when an `if` with no `else` branch is
+/// followed by other statements, e.g. a guard clause.
+pub fn may_nest(block: &BlockStmt) -> bool {
+   let mut v = NestVisitor::default();
+   block.visit_with(&mut v);
+
+   return v.found;
+}
+
+#[derive(Default)]
+struct Visitor {
+   cost: Cost,
+   depth: usize,
+}
+
+impl Visit for Visitor {
+   noop_visit_block_ignored!();
+
+   fn visit_stmt(&mut self, stmt: &Stmt) {
+      if stmt.is_block() {
+         return stmt.visit_children_with(self);
+      }
+      if stmt.is_break_stmt() || stmt.is_labeled() {
+         self.cost.jumps += 1;
+      }
+
+      self.cost.stmts += 1;
+      self.depth += 1;
+      self.cost.depth = self.cost.depth.max(self.depth);
+      stmt.visit_children_with(self);
+      self.depth -= 1;
+   }
+}
+
+#[derive(Default)]
+struct NestVisitor {
+   found: bool,
+}
+
+impl Visit for NestVisitor {
+   noop_visit_block_ignored!();
+
+   fn visit_stmts(&mut self, stmts: &[Stmt]) {
+      let (_, init) = unwrap_or!(stmts.split_last(), return);
+
+      self.found |= init.iter().any(|stmt| {
+         let mut stmt = stmt;
+         // The final `else` of an `else if` chain.
+         while let Stmt::If(IfStmt {
+            alt,
+            ..
+         }) = stmt
+         {
+            stmt = unwrap_or!(alt.as_deref(), return true);
+         }
+         return false;
+      });
+      bail_if!(self.found);
+
+      stmts.visit_children_with(self);
+   }
+}
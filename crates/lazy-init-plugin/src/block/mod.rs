@@ -1,8 +1,11 @@
 use super::*;
 pub use analyzer::{analyze, TransformType};
+pub use compressor::compress;
 pub use visitor::LazyBlockVisitor;
 
 mod analyzer;
+mod compressor;
+mod cost;
 mod flow_analyzer;
 mod stmt_context;
 mod visitor;
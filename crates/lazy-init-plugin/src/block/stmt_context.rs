@@ -1,5 +1,6 @@
 use bitflags::bitflags;
 use core::fmt::Debug;
+use swc_common::Span;
 use swc_ecma_ast::Ident;
 use tracing::debug;
 
@@ -70,6 +71,9 @@ pub enum StmtType {
 pub struct StmtCtx {
    pub kind: StmtType,
    pub flags: Flags,
+   /// The span of the statement, or of the branch for kinds that are not
+   /// statements such as `StmtType::SwitchCase`.
+   pub span: Span,
    /// The child statements within this statement.
    pub children: Vec<StmtCtx>,
    /// The label of a labeled statement that this statement is a child of.
@@ -103,6 +107,49 @@ impl StmtCtx {
       return self.flags.has_any(flag);
    }
 
+   /// Checks if the statement always exits the block through a `return` or
+   /// `throw`, meaning any statement after it can never execute.
+   ///
+   /// Unlike [`Flags::ALWAYS_RETURNS`], an if statement must have an `else`
+   /// branch, and both branches must always exit.
+   pub fn always_exits(&self) -> bool {
+      if self.has(F::FALLS_THROUGH) || !self.has(F::ALWAYS_RETURNS) {
+         return false;
+      }
+
+      let any_exits = |stmts: &Vec<StmtCtx>| {
+         return stmts.iter().any(StmtCtx::always_exits);
+      };
+
+      return match self.kind {
+         StmtType::Return | StmtType::Throw => true,
+         StmtType::If => {
+            self.then_stmts.as_ref().is_some_and(any_exits) &&
+               self.else_stmts.as_ref().is_some_and(any_exits)
+         }
+         StmtType::Block |
+         StmtType::TryBlock |
+         StmtType::TryCatch |
+         StmtType::TryFinally => any_exits(&self.children),
+         StmtType::Try => {
+            let branch = |kind: StmtType| {
+               return self.children.iter().find(|c| return c.kind == kind);
+            };
+            let exits = |kind: StmtType| {
+               return branch(kind).is_some_and(StmtCtx::always_exits);
+            };
+
+            exits(StmtType::TryFinally) ||
+               (exits(StmtType::TryBlock) &&
+                  (branch(StmtType::TryCatch).is_none() ||
+                     exits(StmtType::TryCatch)))
+         }
+         // Loops may never be entered, and the other statements are not
+         // analyzed for simplicity.
+         _ => false,
+      };
+   }
+
    pub fn compute(&mut self) {
       // Fast path may have already computed the effect of this statement.
       bail_if!(self.has_any(F::NO_FLOW_IMPACT | F::FALLS_THROUGH));
@@ -177,7 +224,12 @@ impl StmtCtx {
                if !then_flags.has(F::ALWAYS_RETURNS) ||
                   !else_flags.has(F::ALWAYS_RETURNS)
                {
-                  return self.falls_through();
+                  if self.has(F::RETURNS) {
+                     return self.falls_through();
+                  }
+                  // The branches only throw, so the if statement simply
+                  // continues to the next statement when one does not.
+                  self.rem(F::ALWAYS_RETURNS);
                }
             }
          }
@@ -212,8 +264,9 @@ impl StmtCtx {
                return self.falls_through();
             }
 
-            // The loop will consume these flags.
-            self.rem(F::BREAKS | F::CONTINUES);
+            // The loop will consume these flags. The body of the loop may never
+            // be entered, so the loop cannot always throw.
+            self.rem(F::BREAKS | F::CONTINUES | F::ALWAYS_RETURNS);
          }
 
          // Switch-like.
@@ -240,35 +293,32 @@ impl StmtCtx {
 
             let has_return = self.has(F::RETURNS);
 
-            if has_return {
-               // A break/continue in the presence of a return means the switch
-               // can fall through.
-               if self.has_any(F::CONTINUES | F::BREAKS) {
-                  return self.falls_through();
-               }
-
-               // If any of the children return, they all must always return. No
-               // default case is considered missing a return.
-               if !has_default || !self.children_have(F::ALWAYS_RETURNS) {
-                  return self.falls_through();
-               }
+            // A continue in the presence of a return means the switch can fall
+            // through.
+            if has_return && self.has(F::CONTINUES) {
+               return self.falls_through();
+            }
 
+            // Every case is an entry point, so they all must always return. No
+            // default case is considered missing a return.
+            if has_default && self.cases_always_return() {
                return self.add(F::ALWAYS_RETURNS);
             }
+
+            if has_return {
+               return self.falls_through();
+            }
          }
-         StmtType::SwitchCase => {
-            bail_if!(self.children.is_empty());
-            self.compute_block();
-         }
-         StmtType::SwitchDefault => {
-            bail_if!(self.children.is_empty());
+         StmtType::SwitchCase | StmtType::SwitchDefault => {
+            if self.children.is_empty() {
+               return self.no_flow_impact();
+            }
+
             self.compute_block();
          }
 
          // Try-like.
          StmtType::Try => {
-            // TODO: This section can be simplified.
-
             #[derive(Default)]
             struct TryBranchCtx {
                pub exists: bool,
@@ -322,78 +372,55 @@ impl StmtCtx {
                if finally.exists { "-finally" } else { "" },
             );
 
-            if block.returns {
-               self.add(F::RETURNS);
+            if finally.returns {
+               // A return within the finally block discards any exception
+               // thrown within the try and catch blocks. Once inlined, the
+               // return is only an assignment to the temp variable and the
+               // exception would be thrown instead.
+               debug!(
+                  "Found a return within the finally block. Falling through."
+               );
+               return self.falls_through();
             }
 
-            if block.returns || catch.returns || finally.returns {
-               debug!("Found a return within the try statement.");
-
-               let only_finally_returns =
-                  finally.returns && !(block.returns || catch.returns);
+            // The finally block is executed after the try and catch blocks
+            // complete, so the try statement always returns if the finally
+            // block always throws. Otherwise, both the try and catch blocks
+            // must always return. Without a catch block, an exception thrown
+            // within the try block is not caught.
+            let always_returns = finally.always_returns ||
+               (block.always_returns &&
+                  (catch.always_returns || !catch.exists));
 
-               if only_finally_returns {
-                  // The only return is within the finally block, it must always
-                  // return to avoid falling through. The try/catch block
-                  // doesn't need to return since this will always take
-                  // precedence.
-
-                  debug!("The only return is within the finally block.");
+            if block.returns || catch.returns {
+               debug!("Found a return within the try statement.");
+               self.add(F::RETURNS);
 
-                  if !finally.always_returns {
-                     debug!(
-                        "However, it doesn't always return. Falling through."
-                     );
-                     return self.falls_through();
-                  }
-               } else {
-                  // A return is present in any of the branches which requires
-                  // that the try/catch branches both always return if the
-                  // finally block doesn't.
-                  if !finally.always_returns &&
-                     ((!block.always_returns && block.exists) ||
-                        (!catch.always_returns && catch.exists))
-                  {
-                     debug!(
-                        "All try branches do not always return. Falling \
-                         through."
-                     );
-                     return self.falls_through();
-                  }
+               if !always_returns {
+                  debug!(
+                     "All try branches do not always return. Falling through."
+                  );
+                  return self.falls_through();
                }
             } else {
                debug!("No return found within the try statement.");
             }
 
-            if finally.exists {
-               if finally.throws {
-                  self.add(F::THROWS);
-               }
-               if finally.returns {
-                  self.add(F::RETURNS);
-               }
-               if finally.always_returns {
-                  return self.add(F::ALWAYS_RETURNS);
-               }
+            if finally.throws {
+               self.add(F::THROWS);
             }
-
             if catch.exists {
                if catch.throws && !finally.always_returns {
                   self.add(F::THROWS);
                }
-               if catch.returns {
-                  self.add(F::RETURNS);
-               }
-               if catch.always_returns && block.always_returns {
-                  return self.add(F::ALWAYS_RETURNS);
-               }
             } else {
                // Without a catch block we assume that the try block may throw
-               // even without an explicit throw. If there is a finally block,
-               // it will not handle the throw since if it did, we would have
-               // returned above.
+               // even without an explicit throw.
                self.add(F::THROWS);
             }
+            if always_returns {
+               self.add(F::ALWAYS_RETURNS);
+            }
          }
          StmtType::TryBlock => {
             self.compute_block();
@@ -427,8 +454,6 @@ impl StmtCtx {
       self.flags.add_from(&flags, None);
    }
 
-   // TODO: throw statements should count as a return.
-   // Maybe add ALWAYS_THROWS.
    fn compute_block_like_stmts(stmts: &Vec<StmtCtx>) -> Flags {
       let mut flags = F::default();
 
@@ -471,6 +496,18 @@ impl StmtCtx {
       }
 
       if !flags.has(F::RETURNS) {
+         if !flags.has_any(F::BREAKS | F::CONTINUES) {
+            // The only jumps in the block are throws. An uncaught throw
+            // terminates the block in the same way a return does, so the block
+            // always terminates if the final statement does. Otherwise, it
+            // simply continues to the next statement as there is no return
+            // that would need to be skipped.
+            let final_stmt = stmts.last().expect("No final statement.");
+
+            if !Self::block_falls_through(final_stmt) {
+               flags.add(F::ALWAYS_RETURNS);
+            }
+         }
          // No return statement in the block, but there is a
          // break/continue/throw, so we must later consider this block
          // in the context of a parent statement.
@@ -543,6 +580,37 @@ impl StmtCtx {
       return false;
    }
 
+   /// Checks if entering the switch at any of its cases will always encounter
+   /// a return. A case that completes normally falls through to the next case
+   /// in source order, wherever the `default` case is placed.
+   /// ```ts
+   /// switch (val) {
+   ///    default: // Falls through to `case A`.
+   ///    case A: return 0;
+   ///    case B: log(); // Falls through to `case C`.
+   ///    case C: return 1;
+   /// }
+   /// ```
+   fn cases_always_return(&self) -> bool {
+      // The last case falls out of the switch statement.
+      let mut next_always_returns = false;
+
+      for case in self.children.iter().rev() {
+         let always_returns = if case.has_any(F::BREAKS | F::CONTINUES) {
+            // The case can exit the switch statement.
+            false
+         } else if case.has(F::ALWAYS_RETURNS) {
+            true
+         } else {
+            next_always_returns
+         };
+
+         bail_if!(!always_returns, false);
+         next_always_returns = always_returns;
+      }
+      return true;
+   }
+
    fn falls_through(&mut self) {
       self.add(F::FALLS_THROUGH);
       self.rem(F::ALWAYS_RETURNS);
@@ -567,18 +635,6 @@ impl StmtCtx {
       return self.children.iter().any(|c| return c.has_any(flags));
    }
 
-   /// Checks if ALL of the children have ALL of the given flags.
-   #[inline]
-   fn children_have(&self, flags: Flags) -> bool {
-      return self.children.iter().all(|c| return c.has(flags));
-   }
-
-   /// Checks if ALL of the children have ANY of the given flags.
-   #[inline]
-   fn _children_have_any(&self, flags: Flags) -> bool {
-      return self.children.iter().all(|c| return c.has_any(flags));
-   }
-
    /// Copies all the set flags from the children.
    #[inline]
    fn _add_from_children(&mut self, flags: Option<Flags>) {
@@ -599,6 +655,7 @@ impl Debug for StmtCtx {
       let StmtCtx {
          kind,
          flags,
+         span,
          children,
          label,
          then_stmts,
@@ -609,6 +666,7 @@ impl Debug for StmtCtx {
 
       s.field("kind", &kind)
          .field("flags", &flags)
+         .field("span", &span)
          .field("children", &children);
 
       if let Some(l) = label {
@@ -641,6 +699,8 @@ mod stmt_flags {
          /// There is a throw statement somewhere in the statement.
          const THROWS = 1 << 4;
          /// The statement will always encounter a `return` when it is entered.
+         /// An uncaught `throw` also terminates the statement, so a statement
+         /// where every path ends with either of them will always return.
          /// ```ts
          /// // The use of `#` is to indicate where a block starts and ends.
          /// { // #1
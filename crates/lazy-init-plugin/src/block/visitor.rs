@@ -2,20 +2,23 @@ use either::{
    for_both,
    Either::{self, Left, Right},
 };
-use swc_common::{util::take::Take, Mark, DUMMY_SP};
-use swc_ecma_ast::*;
-use swc_ecma_minifier::{
-   optimize,
-   option::{CompressOptions, ExtraOptions, MinifyOptions},
+use swc_common::{
+   comments::Comments, util::take::Take, Mark, Span, Spanned, DUMMY_SP,
 };
+use swc_ecma_ast::*;
 use swc_ecma_utils::{replace_ident, undefined, ExprFactory};
 use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 
 use super::*;
 use crate::visitor::{LazyVisitor, LzMethodFound};
 use analyzer::{analyze, TransformType};
+use compressor::compress;
+use configs::{BlockFallback, BlockTransform};
+use cost::Cost;
+use diagnostics::{Diagnostic, DiagnosticCode, Severity};
 use lazy_init_pkg::LzMethodKind;
-use normalizer::normalize_block;
+use normalizer::{normalize_block, wrap_with_block};
+use pragmas::Pragma;
 use utils::{BlockLabel, LazyVar};
 use visitor_state_macro::save_state;
 
@@ -24,6 +27,15 @@ pub struct LazyBlockVisitor<'a> {
    main: &'a mut LazyVisitor,
    mode: Mode,
    block: Option<Block>,
+   /// Whether the closest enclosing function is `async`, or there is no
+   /// enclosing function since modules support top-level `await`.
+   inside_async_fn: bool,
+   /// Whether the closest enclosing function is a generator.
+   inside_generator_fn: bool,
+   /// Diagnostics reported while a block is transformed. They are only
+   /// reported once the block replaces the call, since the code they refer
+   /// to is discarded when the original call is restored.
+   diagnostics: Option<Vec<Diagnostic>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -32,16 +44,42 @@ enum Mode {
    Transform,
    /// The visitor is searching for a block and will not perform
    /// transformations. The bool value indicates whether a block was found.
-   /// This is used to determine if we can safely minify a block based on
-   /// whether it contains nested blocks.
    SearchOnly(bool),
 }
 
 #[derive(Debug)]
 struct Block {
    decl_kind: VarDeclKind,
-   temp_var: LazyVar,
+   value: BlockValue,
    stmts: Vec<Stmt>,
+   /// The call before it was transformed, restored when the block falls back
+   /// to calling the function. See [`LazyBlockVisitor::fallback`].
+   original: Option<CallExpr>,
+   /// Diagnostics reported while the block was transformed.
+   diagnostics: Vec<Diagnostic>,
+   /// How the value of the block initializes the declaration.
+   init: BlockInit,
+}
+
+#[derive(Debug)]
+enum BlockValue {
+   /// The value is assigned to the temp variable by the block's statements.
+   TempVar(LazyVar),
+   /// The block's statements were collapsed, the value is the expression that
+   /// is evaluated after them. See [`simplify::collapse`].
+   Expr(Box<Expr>),
+}
+
+/// The expression that a block is called in to initialize a declaration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BlockInit {
+   /// `block(...)`
+   Call,
+   /// `await block(...)`, the value of the block is awaited.
+   Await,
+   /// `yield* block(...)`, the value of the block is delegated to, unless the
+   /// function passed to it is a generator whose value is the inlined block.
+   YieldDelegate,
 }
 
 impl LazyBlockVisitor<'_> {
@@ -53,6 +91,9 @@ impl LazyBlockVisitor<'_> {
          main: lazy_visitor,
          mode: Mode::Transform,
          block: None,
+         inside_async_fn: true,
+         inside_generator_fn: false,
+         diagnostics: None,
       };
       items.visit_mut_with(&mut v);
       return v;
@@ -62,69 +103,278 @@ impl LazyBlockVisitor<'_> {
       &mut self,
       call_expr: &mut CallExpr,
       method: &LzMethodFound,
+      init: BlockInit,
+   ) {
+      // The original call is restored if the block cannot be inlined, so
+      // the block can fall back to calling the function.
+      let original = call_expr.clone();
+
+      // The call itself is kept when the block falls back, so this is
+      // reported before any diagnostics of the block are buffered.
+      self.check_force_wrapped_arg(call_expr, method);
+
+      let outer = self.diagnostics.replace(Vec::new());
+      let result = self.transform_block(call_expr, method, init);
+      let diagnostics = std::mem::replace(&mut self.diagnostics, outer);
+
+      match result {
+         Ok(Some(mut block)) => {
+            block.original = Some(original);
+            block.diagnostics = diagnostics.unwrap_or_default();
+            self.block = Some(block);
+         }
+         Ok(None) => {
+            *call_expr = original;
+            // Any blocks nested within the function are transformed as usual.
+            call_expr.visit_mut_children_with(self);
+         }
+         Err(diagnostic) => {
+            *call_expr = original;
+            self.fallback(call_expr, diagnostic);
+         }
+      }
+   }
+
+   /// Reports the deprecated second argument that forces the block to be
+   /// wrapped, which is replaced by the `/* lazy-init-force-wrapped */`
+   /// comment.
+   fn check_force_wrapped_arg(
+      &mut self,
+      call_expr: &CallExpr,
+      method: &LzMethodFound,
    ) {
+      let extra_args = unwrap_or!(call_expr.args.get(1..), return);
+      let (first, last) =
+         unwrap_or!(extra_args.first().zip(extra_args.last()), return);
+
+      self.report(
+         Diagnostic::new(
+            DiagnosticCode::BlockForceWrappedArgument,
+            first.span().to(last.span()),
+            format!(
+               "Passing a second argument to {} to force the block to be \
+                wrapped is deprecated.",
+               method.format()
+            ),
+         )
+         .with_help(
+            "Place a `/* lazy-init-force-wrapped */` comment before the call \
+             instead."
+               .to_owned(),
+         ),
+      );
+   }
+
+   fn transform_block(
+      &mut self,
+      call_expr: &mut CallExpr,
+      method: &LzMethodFound,
+      init: BlockInit,
+   ) -> Result<Option<Block>, Diagnostic> {
       // TODO: `lz` inside errors should be the actual function name.
 
       let mut lazy_args = call_expr.args.take();
 
       if lazy_args.is_empty() {
-         swc_panic!(
+         return Err(Diagnostic::new(
+            DiagnosticCode::BlockMissingArgument,
             call_expr.span,
-            (
+            format!(
                "Calls to {} must have an argument.",
                method.format()
-            )
-         );
+            ),
+         ));
       }
 
       let block_fn_arg = *lazy_args.remove(0).expr;
-      // TODO: document this behavior.
-      // Any extra argument indicates the user is forcing this block to be
-      // wrapped.
-      let force_wrapped = !lazy_args.is_empty();
-
-      let mut block = match block_fn_arg {
-         Expr::Fn(f) => swc_unwrap!(
-            f.function.body,
-            f.function.span,
-            (
-               "The function passed to {} must have a body.",
-               method.format()
-            )
-         ),
-         Expr::Arrow(mut a) => swc_unwrap!(
-            a.body.as_mut_block_stmt(),
-            a.span,
-            (
-               "The function passed to {} must have a body.",
+      // Passing any value as the second argument is deprecated, see
+      // `LazyBlockVisitor::check_force_wrapped_arg`.
+      let force_wrapped = !lazy_args.is_empty() ||
+         self
+            .main
+            .has_pragma(call_expr.span.lo, Pragma::ForceWrapped);
+
+      let block_transform = self.main.config.block_transform;
+      let block_config = self.main.config.block;
+      let wrap = force_wrapped ||
+         block_transform == BlockTransform::Wrapped ||
+         !block_config.inline;
+
+      // The block is left as a runtime call when the transform it requires
+      // is disabled.
+      bail_if!(wrap && !block_config.wrapped, Ok(None));
+
+      let is_async = match &block_fn_arg {
+         Expr::Fn(f) => f.function.is_async,
+         Expr::Arrow(a) => a.is_async,
+         _ => false,
+      };
+      // The body of an async function is inlined into the enclosing function,
+      // any `await` within it will become an `await` of the enclosing
+      // function.
+      if is_async && !self.inside_async_fn {
+         return Err(Diagnostic::new(
+            DiagnosticCode::BlockAsyncOutsideAsyncFn,
+            block_fn_arg.span(),
+            format!(
+               "An async function can only be passed to {} when it is called \
+                within an async function or at the top level of the module.",
                method.format()
+            ),
+         ));
+      }
+      // The value of the block would otherwise be the promise returned by the
+      // function, which is not created once the body is inlined.
+      if is_async && init != BlockInit::Await {
+         return Err(
+            Diagnostic::new(
+               DiagnosticCode::BlockAsyncNotAwaited,
+               call_expr.span,
+               format!(
+                  "The value of {} must be awaited when it is passed an async \
+                   function.",
+                  method.format()
+               ),
             )
-         )
-         .take(),
-         _ => swc_panic!(
-            call_expr.span,
-            (
-               "The argument passed to {} must be a function expression or \
-                arrow function expression with a body.",
+            .with_help(
+               "Use `await` on the block, e.g. `const a = await block(async \
+                () => { ... });`."
+                  .to_owned(),
+            ),
+         );
+      }
+
+      // Only function expressions can be generators. Likewise, any `yield`
+      // within it will become a `yield` of the enclosing generator.
+      let is_generator = match &block_fn_arg {
+         Expr::Fn(f) => f.function.is_generator,
+         _ => false,
+      };
+      if is_generator && !self.inside_generator_fn {
+         return Err(Diagnostic::new(
+            DiagnosticCode::BlockGeneratorOutsideGeneratorFn,
+            block_fn_arg.span(),
+            format!(
+               "A generator function can only be passed to {} when it is \
+                called within a generator function.",
                method.format()
+            ),
+         ));
+      }
+      // The value of the block would otherwise be the generator object
+      // returned by the function, while `yield*` evaluates to the value it
+      // returns.
+      if is_generator && init != BlockInit::YieldDelegate {
+         return Err(
+            Diagnostic::new(
+               DiagnosticCode::BlockGeneratorNotDelegated,
+               call_expr.span,
+               format!(
+                  "The value of {} must be delegated to using `yield*` when \
+                   it is passed a generator function.",
+                  method.format()
+               ),
             )
-         ),
+            .with_help(
+               "Use `yield*` on the block, e.g. `const a = yield* \
+                block(function* () { ... });`."
+                  .to_owned(),
+            ),
+         );
+      }
+      let init = if is_generator { BlockInit::Call } else { init };
+
+      let mut block = match block_fn_arg {
+         Expr::Fn(f) => {
+            let span = f.function.span;
+            let mut body = unwrap_or!(
+               f.function.body,
+               return Err(Diagnostic::new(
+                  DiagnosticCode::BlockEmpty,
+                  span,
+                  format!(
+                     "The function passed to {} must have a body.",
+                     method.format()
+                  ),
+               ))
+            );
+            // Unlike an arrow function, a function expression has its own
+            // `this`, `arguments` and `new.target` which would otherwise
+            // refer to those of the enclosing function once inlined.
+            let mut v = fn_expr_transform::Visitor::default();
+            body.visit_mut_with(&mut v);
+
+            if let Some((span, name)) = v.unsupported {
+               return Err(
+                  Diagnostic::new(
+                     DiagnosticCode::BlockFnExprUnsupported,
+                     span,
+                     format!(
+                        "`{}` cannot be used within the function expression \
+                         passed to {}.",
+                        name,
+                        method.format()
+                     ),
+                  )
+                  .with_help("Use an arrow function instead.".to_owned()),
+               );
+            }
+            body
+         }
+         Expr::Arrow(a) => match *a.body {
+            BlockStmtOrExpr::BlockStmt(body) => body,
+            // `() => <expr>` => `() => { return <expr>; }`
+            BlockStmtOrExpr::Expr(expr) => BlockStmt {
+               span: a.span,
+               stmts: vec![Stmt::Return(ReturnStmt {
+                  span: expr.span(),
+                  arg: Some(expr),
+               })],
+            },
+         },
+         _ => {
+            return Err(Diagnostic::new(
+               DiagnosticCode::BlockInvalidArgument,
+               call_expr.span,
+               format!(
+                  "The argument passed to {} must be a function expression or \
+                   arrow function expression.",
+                  method.format()
+               ),
+            ));
+         }
       };
-      swc_assert!(
-         !block.stmts.is_empty(),
-         block.span,
-         (
-            "The function passed to {} cannot be empty.",
-            method.format()
-         )
-      );
+      if block.stmts.is_empty() {
+         return Err(Diagnostic::new(
+            DiagnosticCode::BlockEmpty,
+            block.span,
+            format!(
+               "The function passed to {} cannot be empty.",
+               method.format()
+            ),
+         ));
+      }
 
       // Prepare the block.
-      block = normalize_block(self.compress_block(block));
+      scope_transform::transform(&mut block);
+      self.transform_nested(&mut block, is_async, is_generator);
+      block = normalize_block(block);
 
       let mut transform_type = TransformType::Wrapped;
+      let mut unanalyzed = None;
+      let mut unreachable = Vec::new();
+
+      if !wrap {
+         // The analyzer modifies the block for the inline transform, so the
+         // block is kept as is in case the wrapped transform is cheaper. When
+         // it cannot be, only the inline transform is applied.
+         if block_transform == BlockTransform::Auto &&
+            block_config.wrapped &&
+            cost::may_nest(&block)
+         {
+            unanalyzed = Some(block.clone());
+         }
 
-      if !force_wrapped {
          // Analyze the block.
          let mut block_as_stmt = Stmt::Block(block);
          let result = analyze(&mut block_as_stmt);
@@ -137,22 +387,131 @@ impl LazyBlockVisitor<'_> {
          match result {
             Ok(ok) => {
                transform_type = ok.transform_type;
+               bail_if!(
+                  transform_type == TransformType::Wrapped &&
+                     !block_config.wrapped,
+                  Ok(None)
+               );
+
+               if let Some(reason) = ok.wrapped_reason {
+                  if self.main.config.report_wrapped {
+                     self.report(
+                        Diagnostic::new(
+                           DiagnosticCode::BlockWrapped,
+                           reason.span,
+                           reason.kind.message().to_owned(),
+                        )
+                        .with_label(
+                           call_expr.callee.span(),
+                           format!(
+                              "the block passed to {} cannot be inlined",
+                              method.format()
+                           ),
+                        )
+                        .with_note(
+                           "The block is wrapped in a labeled statement \
+                            instead."
+                              .to_owned(),
+                        ),
+                     );
+                  }
+               }
+
+               unreachable = ok.unreachable;
             }
             Err(err) => {
-               swc_panic!(
-                  err.span.unwrap_or_else(|| return call_expr.span),
-                  (
-                     "Encountered an error transforming the block passed to \
-                      {}: {}",
-                     method.format(),
-                     err.msg
+               return Err(
+                  Diagnostic::new(
+                     err.code,
+                     err.span.unwrap_or(call_expr.span),
+                     err.msg,
                   )
+                  .with_label(
+                     call_expr.callee.span(),
+                     format!("the block is passed to {} here", method.format()),
+                  ),
                );
             }
          }
       };
 
-      // Transform the block.
+      // Transform the block. The unreachable code is only removed from the
+      // analyzed block.
+      let mut is_analyzed = !wrap;
+      let (mut block, temp_var) = match unanalyzed {
+         // Both transforms are applied and the cheaper one is used.
+         Some(unanalyzed) if transform_type == TransformType::Inline => {
+            let inline = Self::apply(block, TransformType::Inline);
+            let wrapped = Self::apply(unanalyzed, TransformType::Wrapped);
+
+            let mut cheapest = if self.cost_of(&wrapped) < self.cost_of(&inline)
+            {
+               is_analyzed = false;
+               wrapped
+            } else {
+               inline
+            };
+            self.compress(&mut cheapest);
+            cheapest
+         }
+         _ => {
+            let mut transformed = Self::apply(block, transform_type);
+            self.compress(&mut transformed);
+            transformed
+         }
+      };
+
+      for span in unreachable {
+         let diagnostic = Diagnostic::new(
+            DiagnosticCode::UnreachableCode,
+            span,
+            format!(
+               "Unreachable code detected in the block passed to {}.",
+               method.format()
+            ),
+         );
+         self.report(if is_analyzed {
+            diagnostic.with_note(
+               "The code is removed when the block is inlined.".to_owned(),
+            )
+         } else {
+            diagnostic
+         });
+      }
+
+      // Simplify the transformed block.
+      let value = match simplify::collapse(
+         &mut block.stmts,
+         &temp_var,
+         self.main.comments(),
+      ) {
+         Some(expr) => BlockValue::Expr(expr),
+         None => BlockValue::TempVar(temp_var),
+      };
+
+      return Ok(Some(Block {
+         decl_kind: VarDeclKind::Const,
+         value,
+         stmts: block.stmts.take(),
+         original: None,
+         diagnostics: Vec::new(),
+         init,
+      }));
+   }
+
+   /// Reports a diagnostic, or buffers it while a block is transformed. See
+   /// [`LazyBlockVisitor::diagnostics`].
+   fn report(&mut self, diagnostic: Diagnostic) {
+      match &mut self.diagnostics {
+         Some(diagnostics) => diagnostics.push(diagnostic),
+         None => self.main.report(diagnostic),
+      }
+   }
+
+   fn apply(
+      mut block: BlockStmt,
+      transform_type: TransformType,
+   ) -> (BlockStmt, LazyVar) {
       let temp_var = match transform_type {
          TransformType::Inline => {
             let mut v = inline_transform::Visitor::default();
@@ -162,108 +521,349 @@ impl LazyBlockVisitor<'_> {
          }
          TransformType::Wrapped => {
             let mut v = wrapped_transform::Visitor::default();
+            v.span = block.span;
 
             block.visit_mut_with(&mut v);
             v.temp_var
          }
       };
 
-      self.block = Some(Block {
-         decl_kind: VarDeclKind::Const,
-         temp_var,
-         stmts: block.stmts.take(),
+      return (block, temp_var);
+   }
+
+   /// Compresses the transformed block when enabled.
+   fn compress(&self, (block, temp_var): &mut (BlockStmt, LazyVar)) {
+      if self.main.config.compress_blocks {
+         compress(
+            &mut block.stmts,
+            &temp_var.ident,
+            self.main.comments(),
+         );
+      }
+   }
+
+   /// The cost of the transformed block once compressed. The comments are
+   /// shared by both transforms, so they are only moved when the chosen
+   /// block is compressed.
+   fn cost_of(&self, (block, temp_var): &(BlockStmt, LazyVar)) -> usize {
+      let mut stmts = block.stmts.clone();
+
+      if self.main.config.compress_blocks {
+         compress(&mut stmts, &temp_var.ident, None);
+      }
+      return Cost::of(&stmts).score();
+   }
+
+   /// Called when a block cannot be inlined. Depending on the
+   /// `blockFallback` option, the diagnostic is reported as an error, a
+   /// warning or not at all. The call is then replaced with a call to the
+   /// function passed to the block, which only matters when the build does
+   /// not fail.
+   fn fallback(&mut self, call_expr: &mut CallExpr, diagnostic: Diagnostic) {
+      match self.main.config.block_fallback {
+         BlockFallback::Error => self.report(diagnostic),
+         BlockFallback::Warn => {
+            self.report(
+               diagnostic.with_severity(Severity::Warning).with_note(
+                  "The function passed to the block is called instead."
+                     .to_owned(),
+               ),
+            );
+         }
+         BlockFallback::Silent => {}
+      }
+
+      // `block(() => { ... })` => `(() => { ... })()`
+      if let Some(ExprOrSpread {
+         spread: None,
+         expr,
+      }) = call_expr.args.first_mut()
+      {
+         if matches!(**expr, Expr::Fn(_) | Expr::Arrow(_)) {
+            call_expr.callee = expr.take().as_callee();
+            call_expr.args.clear();
+         }
+      }
+      // Any blocks nested within the function are transformed as usual.
+      call_expr.visit_mut_children_with(self);
+   }
+
+   /// Transforms the blocks nested within the body of a block before the body
+   /// itself is transformed. The body then only contains the flattened
+   /// statements, which allows it to be analyzed and compressed as a whole.
+   #[save_state(inside_async_fn, inside_generator_fn)]
+   fn transform_nested(
+      &mut self,
+      block: &mut BlockStmt,
+      is_async: bool,
+      is_generator: bool,
+   ) {
+      // The body is inlined into the enclosing function, but until then it is
+      // executed like the function passed to the block.
+      self.inside_async_fn = is_async;
+      self.inside_generator_fn = is_generator;
+      block.visit_mut_children_with(self);
+   }
+
+   /// Returns `true` if a block is found within the children of `node`. No
+   /// transformations are performed. Functions are not searched, since a
+   /// block within them is not evaluated along with `node`.
+   fn search_for_block<N: VisitMutWith<Self>>(&mut self, node: &mut N) -> bool {
+      let prev_mode =
+         std::mem::replace(&mut self.mode, Mode::SearchOnly(false));
+      node.visit_mut_children_with(self);
+
+      let searched = std::mem::replace(&mut self.mode, prev_mode);
+      return matches!(searched, Mode::SearchOnly(true));
+   }
+
+   /// Splits a variable declaration containing multiple declarators so that
+   /// each declarator initialized using a block is declared on its own, while
+   /// preserving the order of the declarators.
+   ///
+   /// ```js
+   /// let a = 1, b = block(() => { ... }), c = 2, d = 3;
+   /// // is split into:
+   /// let a = 1;
+   /// let b = block(() => { ... });
+   /// let c = 2, d = 3;
+   /// ```
+   ///
+   /// Returns `None` if the statement does not need to be split.
+   fn split_var_decl<T: StmtLike>(&mut self, stmt: &mut T) -> Option<Vec<T>> {
+      let var_decl = stmt.as_mut_var_decl()?;
+      bail_if!(var_decl.decls.len() < 2, None);
+
+      let has_block: Vec<bool> = var_decl
+         .decls
+         .iter_mut()
+         .map(|declarator| return self.search_for_block(declarator))
+         .collect();
+
+      bail_if!(!has_block.contains(&true), None);
+
+      let mut groups: Vec<Vec<VarDeclarator>> = Vec::new();
+      let mut current: Vec<VarDeclarator> = Vec::new();
+
+      for (declarator, has_block) in var_decl.decls.drain(..).zip(has_block) {
+         if has_block {
+            if !current.is_empty() {
+               groups.push(current.take());
+            }
+            groups.push(vec![declarator]);
+         } else {
+            current.push(declarator);
+         }
+      }
+      if !current.is_empty() {
+         groups.push(current);
+      }
+
+      let (span, kind, declare) =
+         (var_decl.span, var_decl.kind, var_decl.declare);
+
+      return Some(
+         groups
+            .into_iter()
+            .map(|decls| {
+               return stmt.with_var_decl(VarDecl {
+                  span,
+                  kind,
+                  declare,
+                  decls,
+               });
+            })
+            .collect(),
+      );
+   }
+
+   /// Splits a `return` statement whose argument is a block, so the block is
+   /// used to initialize a declaration.
+   ///
+   /// ```js
+   /// return block(() => { ... });
+   /// // is split into:
+   /// let lv = block(() => { ... });
+   /// return lv;
+   /// ```
+   ///
+   /// Returns `None` if the statement does not need to be split.
+   fn split_return<T: StmtLike>(&mut self, stmt: &mut T) -> Option<Vec<T>> {
+      if let Stmt::Return(ReturnStmt {
+         arg: Some(arg),
+         ..
+      }) = stmt.as_mut_stmt()?
+      {
+         bail_if!(!self.is_block_call(arg), None);
+
+         let arg = arg.take();
+         utils::relocate_comments(
+            self.main.comments(),
+            stmt.as_mut_stmt()?,
+            arg.span(),
+         );
+         let split = Self::return_via_var(arg);
+         return Some(split.stmts.into_iter().map(T::from_stmt).collect());
+      }
+      return None;
+   }
+
+   /// Wraps a declaration or `return` statement containing a block with a
+   /// block statement when it is placed where a single statement is expected.
+   /// This is required since the block must trigger `visit_mut_stmts` for its
+   /// statements to be inserted.
+   ///
+   /// ```js
+   /// if (cond) var val = block(() => { ... });
+   /// // is transformed to:
+   /// if (cond) { var val = block(() => { ... }); }
+   /// ```
+   fn normalize_stmt(&mut self, stmt: &mut Stmt) {
+      let mut wrap_if_block_decl = |body: &mut Stmt| {
+         if matches!(body, Stmt::Decl(Decl::Var(_)) | Stmt::Return(_)) &&
+            self.search_for_block(body)
+         {
+            wrap_with_block(body);
+         }
+      };
+
+      match stmt {
+         Stmt::If(stmt) => {
+            wrap_if_block_decl(&mut stmt.cons);
+
+            if let Some(alt) = stmt.alt.as_mut() {
+               wrap_if_block_decl(alt);
+            }
+         }
+         Stmt::DoWhile(stmt) => wrap_if_block_decl(&mut stmt.body),
+         Stmt::For(stmt) => wrap_if_block_decl(&mut stmt.body),
+         Stmt::ForIn(stmt) => wrap_if_block_decl(&mut stmt.body),
+         Stmt::ForOf(stmt) => wrap_if_block_decl(&mut stmt.body),
+         Stmt::While(stmt) => wrap_if_block_decl(&mut stmt.body),
+         Stmt::With(stmt) => wrap_if_block_decl(&mut stmt.body),
+         Stmt::Labeled(stmt) => wrap_if_block_decl(&mut stmt.body),
+         _ => {}
+      }
+   }
+
+   /// Converts the expression body of an arrow function that is a block into a
+   /// block statement body, so the block has statements to be inserted into.
+   ///
+   /// ```js
+   /// const fn = () => block(() => { ... });
+   /// // is transformed to:
+   /// const fn = () => {
+   ///    let lv = block(() => { ... });
+   ///    return lv;
+   /// };
+   /// ```
+   fn normalize_arrow_body(&mut self, arrow_expr: &mut ArrowExpr) {
+      let expr = unwrap_or!(arrow_expr.body.as_mut_expr(), return);
+      bail_if!(!self.is_block_call(expr));
+
+      *arrow_expr.body =
+         BlockStmtOrExpr::BlockStmt(Self::return_via_var(expr.take()));
+   }
+
+   fn is_block_call(&self, expr: &mut Expr) -> bool {
+      return expr.as_mut_call().map_or(false, |call_expr| {
+         return self.find_block_method(call_expr).is_some();
       });
    }
 
-   fn compress_block(&mut self, mut block: BlockStmt) -> BlockStmt {
-      // User may have disabled block compression.
-      bail_if!(!self.main.config.compress_blocks, block);
-      // If a nested block is found, we cannot safely compress the block.
-      bail_if!(self.search_for_nested(&mut block), block);
-
-      let func = Expr::Fn(FnExpr {
-         ident: None,
-         function: Box::new(Function {
-            span: DUMMY_SP,
-            params: vec![],
-            decorators: vec![],
-            body: Some(block),
-            is_generator: false,
-            is_async: false,
-            type_params: None,
-            return_type: None,
+   /// Returns the call that initializes a declaration, and the expression it
+   /// is called in.
+   fn init_call(init: &mut Expr) -> Option<(&mut CallExpr, BlockInit)> {
+      return match init {
+         Expr::Call(call_expr) => Some((call_expr, BlockInit::Call)),
+         Expr::Await(AwaitExpr {
+            arg,
+            ..
+         }) => arg.as_mut_call().map(|c| return (c, BlockInit::Await)),
+         Expr::Yield(YieldExpr {
+            arg: Some(arg),
+            delegate: true,
+            ..
+         }) => arg
+            .as_mut_call()
+            .map(|c| return (c, BlockInit::YieldDelegate)),
+         _ => None,
+      };
+   }
+
+   /// Initializes a declaration using the value of a block, see
+   /// [`BlockInit`].
+   fn init_value(value: Box<Expr>, init: BlockInit) -> Expr {
+      let span = value.span();
+
+      return match init {
+         BlockInit::Call => *value,
+         BlockInit::Await => Self::await_value(value),
+         BlockInit::YieldDelegate => Expr::Yield(YieldExpr {
+            span,
+            arg: Some(value),
+            delegate: true,
          }),
+      };
+   }
+
+   /// `await <value>`. The value of an awaited block may be a promise, e.g.
+   /// when the function passed to it is async, which is resolved once the
+   /// inlined statements have been executed.
+   fn await_value(value: Box<Expr>) -> Expr {
+      // The result of an `await` is never a promise.
+      bail_if!(value.is_await_expr(), *value);
+
+      let span = value.span();
+      let arg = match *value {
+         Expr::Ident(_) | Expr::Lit(_) | Expr::Call(_) | Expr::Member(_) => {
+            value
+         }
+         _ => Box::new(Expr::Paren(ParenExpr {
+            span,
+            expr: value,
+         })),
+      };
+      return Expr::Await(AwaitExpr {
+         span,
+         arg,
       });
+   }
 
-      let mut program = Program::Script(Script {
-         span: DUMMY_SP,
-         body: vec![Stmt::Expr(ExprStmt {
-            span: DUMMY_SP,
-            expr: Box::new(func.make_assign_to(
-               AssignOp::AddAssign,
-               PatOrExpr::Pat(Box::new(Pat::Ident(BindingIdent {
-                  id: Ident {
-                     span: DUMMY_SP,
-                     sym: "globalThis".into(),
-                     optional: false,
-                  },
-                  type_ann: None,
-               }))),
-            )),
-         })],
-         shebang: None,
+   fn find_block_method(
+      &self,
+      call_expr: &mut CallExpr,
+   ) -> Option<LzMethodFound> {
+      return self.main.find_lazy_method(call_expr).filter(|m| {
+         return m.kind == LzMethodKind::Block &&
+            self.main.config.is_enabled(m.kind);
       });
+   }
 
-      program = optimize(
-         program,
-         std::sync::Arc::default(),
-         None,
-         None,
-         &MinifyOptions {
-            compress: Some(CompressOptions {
-               keep_classnames: true,
-               keep_fargs: true,
-               keep_fnames: true,
-               keep_infinity: true,
-               module: false,
-               ..Default::default()
+   /// Creates a block statement that declares a variable initialized using
+   /// the expression, then returns it. Both statements have the span of the
+   /// expression.
+   fn return_via_var(expr: Box<Expr>) -> BlockStmt {
+      let span = expr.span();
+      let mut lazy_var = LazyVar::new();
+      lazy_var.declarator.span = span;
+      lazy_var.declarator.init = Some(expr);
+
+      return BlockStmt {
+         span,
+         stmts: vec![
+            Stmt::Decl(Decl::Var(Box::new(VarDecl {
+               span,
+               kind: VarDeclKind::Let,
+               declare: false,
+               decls: vec![lazy_var.declarator.clone()],
+            }))),
+            Stmt::Return(ReturnStmt {
+               span,
+               arg: Some(Box::new(Expr::Ident(lazy_var.ident_at(span)))),
             }),
-            mangle: None,
-            enclose: false,
-            rename: false,
-            wrap: false,
-         },
-         &ExtraOptions {
-            unresolved_mark: self.main.metadata.unresolved_mark,
-            top_level_mark: Mark::new(),
-         },
-      );
-
-      #[allow(clippy::unwrap_used)]
-      {
-         block = program.expect_script().body[0]
-            .as_mut_expr()
-            .unwrap()
-            .expr
-            .as_mut_assign()
-            .unwrap()
-            .right
-            .as_mut_fn_expr()
-            .unwrap()
-            .function
-            .body
-            .take()
-            .unwrap();
-      }
-      return block;
-   }
-
-   fn search_for_nested(&mut self, block: &mut BlockStmt) -> bool {
-      self.mode = Mode::SearchOnly(false);
-      block.visit_mut_children_with(self);
-
-      let mode = std::mem::replace(&mut self.mode, Mode::Transform);
-      return matches!(mode, Mode::SearchOnly(true));
+         ],
+      };
    }
 
    fn skipping(&self) -> bool {
@@ -274,61 +874,89 @@ impl LazyBlockVisitor<'_> {
       return matches!(&self.mode, Mode::SearchOnly(_));
    }
 
-   // TODO: Single assignment should be collapsed to reduce size.
-   // TODO: Warn user that lz.block must be used inside a block statement.
-   // For example:
-   // `if (true) { var val = lz.block(() => { ... }) }` is OK, but
-   // `if (true) var val = lz.block(() => { ... })` is not.
-   // This is because we need the block to trigger `visit_mut_stmts`.
    fn visit_stmts_like(
       &mut self,
       mut stmts: Either<&mut Vec<Stmt>, &mut Vec<ModuleItem>>,
    ) {
-      bail_if!(self.skipping() || for_both!(&stmts, s => s.is_empty()));
-
-      if self.search_only() {
-         return for_both!(stmts, s => s.visit_mut_children_with(self));
-      }
+      // Statements within an expression can only be the body of a function,
+      // which is not searched.
+      bail_if!(self.search_only() || for_both!(&stmts, s => s.is_empty()));
 
       let mut block_stmts: Option<Vec<Stmt>> = None;
       let mut cursor = 0;
 
       'outer: loop {
          let mut inserted_temp_var = false;
+         let mut split_stmt = false;
 
          for_both!(stmts.as_mut(), stmts => {
+            let mut split_at = None;
+
             // Note: index is relative to the cursor (stmts[cursor..]).
             // The actual index is `idx + cursor`.
             for (idx, stmt) in stmts[cursor..].iter_mut().enumerate() {
+               let split = self
+                  .split_var_decl(stmt)
+                  .or_else(|| return self.split_return(stmt));
+
+               if let Some(split) = split {
+                  split_at = Some((idx + cursor, split));
+                  break;
+               }
+
                stmt.visit_mut_with(self);
 
                let mut block = unwrap_or!(self.block.take(), continue);
 
-               if block.decl_kind == VarDeclKind::Const {
-                  // `const` declarations require that the temp variable
-                  // is declared then used to
-                  // initialize the `const`.
-                  block.stmts.insert(
-                     0,
-                     Stmt::Decl(utils::create_var_declaration(vec![block
-                        .temp_var
-                        .declarator
-                        .clone()])),
-                  );
-                  cursor += idx;
-                  inserted_temp_var = true;
-               } else {
-                  // Statements that are targeting a `let` declaration
-                  // need to be placed after the
-                  // declaration (1 more than the
-                  // current index).
-                  cursor = idx + cursor + 1;
+               for diagnostic in block.diagnostics.take() {
+                  self.report(diagnostic);
+               }
+
+               match (&block.value, block.decl_kind) {
+                  (BlockValue::Expr(_), _) => {
+                     // The declaration is initialized using the collapsed
+                     // expression, which must be evaluated after the
+                     // statements.
+                     cursor += idx;
+                  }
+                  (BlockValue::TempVar(temp_var), VarDeclKind::Const) => {
+                     // `const` declarations require that the temp variable
+                     // is declared then used to
+                     // initialize the `const`.
+                     block.stmts.insert(
+                        0,
+                        Stmt::Decl(utils::create_var_declaration(vec![
+                           temp_var.declarator.clone(),
+                        ])),
+                     );
+                     cursor += idx;
+                     inserted_temp_var = true;
+                  }
+                  _ => {
+                     // Statements that are targeting a `let` declaration
+                     // need to be placed after the
+                     // declaration (1 more than the
+                     // current index).
+                     cursor = idx + cursor + 1;
+                  }
                }
                block_stmts = Some(block.stmts);
                break;
             }
+
+            if let Some((at, split)) = split_at {
+               // Replace the statement with the split statements and
+               // continue visiting from the first of them.
+               stmts.splice(at..=at, split);
+               cursor = at;
+               split_stmt = true;
+            }
          });
 
+         if split_stmt {
+            continue 'outer;
+         }
+
          if let Some(mut block_stmts) = block_stmts.take() {
             match stmts.as_mut() {
                Left(stmts) => {
@@ -473,9 +1101,31 @@ impl LazyBlockVisitor<'_> {
    // }
 }
 
+/// Visits nodes with a body that is executed like a function that can never be
+/// `async` or a generator.
+macro_rules! visit_mut_plain_fn_like {
+   ($( [$name:ident, $N:tt] ),+) => {
+      $(
+         #[save_state(inside_async_fn, inside_generator_fn)]
+         fn $name(&mut self, n: &mut $N) {
+            self.inside_async_fn = false;
+            self.inside_generator_fn = false;
+            n.visit_mut_children_with(self);
+         }
+      )*
+   };
+}
+
 impl VisitMut for LazyBlockVisitor<'_> {
    noop_visit_mut_type!();
 
+   visit_mut_plain_fn_like!(
+      [visit_mut_constructor, Constructor],
+      [visit_mut_getter_prop, GetterProp],
+      [visit_mut_setter_prop, SetterProp],
+      [visit_mut_static_block, StaticBlock]
+   );
+
    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
       self.visit_stmts_like(Left(stmts));
    }
@@ -484,20 +1134,58 @@ impl VisitMut for LazyBlockVisitor<'_> {
       self.visit_stmts_like(Right(module_items));
    }
 
-   fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+   fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
       bail_if!(self.skipping());
 
-      // With blocks we want to transform the outermost block first to make
-      // the analysis easier. Therefore, we skip visiting the children if a
-      // block was found and will visit them later.
+      if !self.search_only() {
+         self.normalize_stmt(stmt);
+      }
+      stmt.visit_mut_children_with(self);
+   }
 
-      if let Some(method) = self.main.find_lazy_method(call_expr) {
-         if method.kind == LzMethodKind::Block {
-            if self.search_only() {
-               return self.mode = Mode::SearchOnly(true);
-            }
-            return self.handle_block(call_expr, &method);
+   #[save_state(inside_async_fn, inside_generator_fn)]
+   fn visit_mut_arrow_expr(&mut self, arrow_expr: &mut ArrowExpr) {
+      // The body is not searched, see `LazyBlockVisitor::search_for_block`.
+      bail_if!(self.search_only());
+
+      if !self.search_only() {
+         self.normalize_arrow_body(arrow_expr);
+      }
+      self.inside_async_fn = arrow_expr.is_async;
+      self.inside_generator_fn = false;
+      arrow_expr.visit_mut_children_with(self);
+   }
+
+   #[save_state(inside_async_fn, inside_generator_fn)]
+   fn visit_mut_function(&mut self, function: &mut Function) {
+      self.inside_async_fn = function.is_async;
+      self.inside_generator_fn = function.is_generator;
+      function.visit_mut_children_with(self);
+   }
+
+   fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+      bail_if!(self.skipping());
+
+      if let Some(method) = self.find_block_method(call_expr) {
+         if self.search_only() {
+            return self.mode = Mode::SearchOnly(true);
          }
+         // Blocks that initialize a declaration are handled by
+         // `visit_mut_var_decl`, any other position is not supported.
+         return self.fallback(
+            call_expr,
+            Diagnostic::new(
+               DiagnosticCode::BlockInvalidPosition,
+               call_expr.span,
+               format!(
+                  "{} must be used to initialize a variable declaration.",
+                  method.format()
+               ),
+            )
+            .with_help(
+               "Example: `const a = lz.block(() => { ... });`.".to_owned(),
+            ),
+         );
       }
 
       call_expr.visit_mut_children_with(self);
@@ -510,40 +1198,99 @@ impl VisitMut for LazyBlockVisitor<'_> {
          return var_decl.visit_mut_children_with(self);
       }
 
-      let declarations = &mut var_decl.decls;
-      let is_single_decl = declarations.len() == 1;
+      let (span, kind) = (var_decl.span, var_decl.kind);
+      let is_single_decl = var_decl.decls.len() == 1;
 
       // Visit declarations and check if a lazy block was found.
-      for declarator in declarations {
-         declarator.visit_mut_with(self);
+      for declarator in &mut var_decl.decls {
+         let (call_expr, init) = unwrap_or!(
+            declarator.init.as_deref_mut().and_then(Self::init_call),
+            {
+               declarator.visit_mut_with(self);
+               continue;
+            }
+         );
+         let method = unwrap_or!(self.find_block_method(call_expr), {
+            declarator.visit_mut_with(self);
+            continue;
+         });
+
+         if !is_single_decl {
+            self.fallback(
+               call_expr,
+               Diagnostic::new(
+                  DiagnosticCode::BlockMultipleDeclarators,
+                  span,
+                  "Multiple declarations are not supported when initalizing a \
+                   `block`."
+                     .to_owned(),
+               )
+               .with_help(
+                  "Example: `let a = lz.block(() => { ... });` is OK, but \
+                   `let a = 1, b = lz.block(() => { ... });` is not."
+                     .to_owned(),
+               ),
+            );
+            continue;
+         }
+
+         self.handle_block(call_expr, &method, init);
 
          let block = unwrap_or!(self.block.as_mut(), continue);
-         block.decl_kind = var_decl.kind;
-
-         swc_assert!(
-            is_single_decl,
-            var_decl.span,
-            ("Multiple declarations are not supported when initalizing a \
-              `block`. Example: `let a = lz.block(() => {{ ... }});` is OK, \
-              but `let a = 1, b = lz.block(() => {{ ... }});` is not.")
-         );
+         // The value of an awaited or delegated block is used in an
+         // expression when initializing the declaration, so it is handled the
+         // same as a `const`.
+         block.decl_kind = if block.init == BlockInit::Call {
+            kind
+         } else {
+            VarDeclKind::Const
+         };
+
+         let block_init = block.init;
+         let init = |value: Box<Expr>| {
+            return Box::new(Self::init_value(value, block_init));
+         };
 
-         if var_decl.kind == VarDeclKind::Const {
+         let temp_var = match &mut block.value {
+            BlockValue::TempVar(temp_var) => temp_var,
+            BlockValue::Expr(expr) => {
+               // The block was collapsed, the declaration can be directly
+               // initialized using the expression.
+               return declarator.init = Some(init(expr.take()));
+            }
+         };
+
+         if block.decl_kind == VarDeclKind::Const {
             // The decl is a `const`, we cannot mutate the variable so we must
             // initialize the declarator using the temp identifier.
-            return declarator.init =
-               Some(Box::new(Expr::Ident(block.temp_var.get_ident())));
+            return declarator.init = Some(init(Box::new(Expr::Ident(
+               temp_var.ident_at(call_expr.span),
+            ))));
          }
          // The decl is a `let`, we can replace the temp identifer with the
          // actual declarator's identifier since we can mutate the variable.
-         let actual_ident = swc_unwrap!(
-            declarator.name.as_ident(),
-            var_decl.span,
-            ("The LHS of a variable declaration that is initalized using a \
-              `block` must be a named identifier. Destructuring patterns are \
-              not supported.")
-         );
-         let block_id = block.temp_var.get_ident().to_id();
+         let actual_ident = unwrap_or!(declarator.name.as_ident(), {
+            // Restore the original call.
+            if let Some(original) = block.original.take() {
+               *call_expr = original;
+            }
+            self.block = None;
+
+            return self.fallback(
+               call_expr,
+               Diagnostic::new(
+                  DiagnosticCode::BlockDestructuring,
+                  span,
+                  "The LHS of a variable declaration that is initalized using \
+                   a `block` must be a named identifier."
+                     .to_owned(),
+               )
+               .with_note(
+                  "Destructuring patterns are not supported.".to_owned(),
+               ),
+            );
+         });
+         let block_id = temp_var.get_ident().to_id();
 
          for stmt in &mut block.stmts {
             replace_ident(stmt, block_id.clone(), actual_ident);
@@ -553,6 +1300,311 @@ impl VisitMut for LazyBlockVisitor<'_> {
    }
 }
 
+/// Preserves the semantics of the body of a function expression passed to a
+/// block when it is inlined into the enclosing function.
+///
+/// The function is called without a receiver in strict mode, so `this` and
+/// `new.target` are replaced with `undefined`. Uses of `arguments` and `super`
+/// cannot be preserved and are reported as unsupported.
+///
+/// Nested functions are not visited since they have their own `this`, however,
+/// nested arrow functions are since they inherit it.
+mod fn_expr_transform {
+   use super::*;
+
+   #[derive(Debug, Default)]
+   pub struct Visitor {
+      /// The span and name of the first unsupported node found.
+      pub unsupported: Option<(Span, &'static str)>,
+   }
+
+   impl Visitor {
+      fn unsupported(&mut self, span: Span, name: &'static str) {
+         self.unsupported.get_or_insert((span, name));
+      }
+   }
+
+   impl VisitMut for Visitor {
+      noop_visit_mut_type!();
+
+      // These have their own `this`.
+      fn visit_mut_function(&mut self, _: &mut Function) {}
+
+      fn visit_mut_constructor(&mut self, _: &mut Constructor) {}
+
+      fn visit_mut_getter_prop(&mut self, getter: &mut GetterProp) {
+         getter.key.visit_mut_with(self);
+      }
+
+      fn visit_mut_setter_prop(&mut self, setter: &mut SetterProp) {
+         setter.key.visit_mut_with(self);
+      }
+
+      fn visit_mut_class(&mut self, class: &mut Class) {
+         // Only the heritage and computed keys are evaluated in the enclosing
+         // scope.
+         class.super_class.visit_mut_with(self);
+
+         for member in &mut class.body {
+            match member {
+               ClassMember::Method(m) => m.key.visit_mut_with(self),
+               ClassMember::ClassProp(p) => p.key.visit_mut_with(self),
+               _ => {}
+            }
+         }
+      }
+
+      fn visit_mut_expr(&mut self, expr: &mut Expr) {
+         expr.visit_mut_children_with(self);
+
+         match expr {
+            // The function is called without a receiver, so `this` is
+            // `undefined` in strict code. Only modules are transformed, which
+            // are always strict, see `LazyVisitor::visit_mut_script`.
+            Expr::This(ThisExpr {
+               span,
+            }) |
+            Expr::MetaProp(MetaPropExpr {
+               span,
+               kind: MetaPropKind::NewTarget,
+            }) => {
+               *expr = *undefined(*span);
+            }
+            Expr::Ident(ident) if &*ident.sym == "arguments" => {
+               self.unsupported(ident.span, "arguments");
+            }
+            _ => {}
+         }
+      }
+
+      fn visit_mut_prop(&mut self, prop: &mut Prop) {
+         prop.visit_mut_children_with(self);
+
+         if let Prop::Shorthand(ident) = prop {
+            if &*ident.sym == "arguments" {
+               self.unsupported(ident.span, "arguments");
+            }
+         }
+      }
+
+      fn visit_mut_super(&mut self, node: &mut Super) {
+         self.unsupported(node.span, "super");
+      }
+   }
+}
+
+/// Gives the bindings declared by a block fresh hygienic identifiers, so once
+/// inlined they can never collide with, shadow or be referenced by the bindings
+/// of the enclosing scope.
+///
+/// `var` declarations are scoped to the function passed to the block and are
+/// `undefined` each time it's called. To preserve this when inlined, they are
+/// hoisted into a single `let` declaration at the start of the block and the
+/// original declarations are replaced with assignments.
+///
+/// ```js
+/// const val = block(() => {
+///    if (cond) { var x = 1; }
+///    return x;
+/// });
+/// // is transformed to (before inlining):
+/// const val = block(() => {
+///    let x;
+///    if (cond) { x = 1; }
+///    return x;
+/// });
+/// ```
+mod scope_transform {
+   use super::*;
+   use std::collections::HashMap;
+   use swc_common::SyntaxContext;
+   use swc_ecma_utils::find_pat_ids;
+   use swc_ecma_visit::visit_mut_obj_and_computed;
+
+   pub fn transform(block: &mut BlockStmt) {
+      let mut hoister = VarHoister::default();
+      block.visit_mut_children_with(&mut hoister);
+
+      if !hoister.vars.is_empty() {
+         block.stmts.insert(
+            0,
+            Stmt::Decl(Decl::Var(Box::new(VarDecl {
+               span: DUMMY_SP,
+               kind: VarDeclKind::Let,
+               declare: false,
+               decls: hoister
+                  .vars
+                  .into_iter()
+                  .map(|ident| {
+                     return VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(ident.into()),
+                        init: None,
+                        definite: false,
+                     };
+                  })
+                  .collect(),
+            }))),
+         );
+      }
+
+      // All `var` declarations are now hoisted, so the bindings of the block
+      // are those declared by its top level statements.
+      let mut renamer = Renamer::default();
+
+      for stmt in &block.stmts {
+         let ids: Vec<Id> = match stmt {
+            Stmt::Decl(Decl::Var(var_decl)) => find_pat_ids(&var_decl.decls),
+            Stmt::Decl(Decl::Fn(fn_decl)) => vec![fn_decl.ident.to_id()],
+            Stmt::Decl(Decl::Class(class_decl)) => {
+               vec![class_decl.ident.to_id()]
+            }
+            _ => continue,
+         };
+
+         for id in ids {
+            let fresh_ctxt = id.1.apply_mark(Mark::new());
+            renamer.map.insert(id, fresh_ctxt);
+         }
+      }
+
+      if !renamer.map.is_empty() {
+         block.visit_mut_with(&mut renamer);
+      }
+   }
+
+   #[derive(Debug, Default)]
+   struct VarHoister {
+      /// The bindings of all the `var` declarations found.
+      vars: Vec<Ident>,
+   }
+
+   impl VarHoister {
+      /// Collects the bindings of a `var` declaration and returns the
+      /// assignments of its initializers, if any.
+      fn hoist(&mut self, var_decl: &mut VarDecl) -> Option<Box<Expr>> {
+         let mut exprs: Vec<Box<Expr>> = Vec::new();
+
+         for declarator in var_decl.decls.drain(..) {
+            let mut name = self.hoist_pat(declarator.name);
+
+            if let Some(init) = declarator.init {
+               exprs.push(Box::new(init.make_assign_to(
+                  op!("="),
+                  PatOrExpr::Pat(Box::new(name.take())),
+               )));
+            }
+         }
+
+         return match exprs.len() {
+            0 => None,
+            1 => exprs.pop(),
+            _ => Some(Box::new(Expr::Seq(SeqExpr {
+               span: var_decl.span,
+               exprs,
+            }))),
+         };
+      }
+
+      fn hoist_pat(&mut self, mut pat: Pat) -> Pat {
+         for ident in find_pat_ids::<_, Ident>(&pat) {
+            let id = ident.to_id();
+
+            if !self.vars.iter().any(|v| return v.to_id() == id) {
+               self.vars.push(Ident::new(ident.sym, ident.span));
+            }
+         }
+         // Type annotations are not valid in an assignment.
+         match &mut pat {
+            Pat::Ident(p) => p.type_ann = None,
+            Pat::Array(p) => p.type_ann = None,
+            Pat::Object(p) => p.type_ann = None,
+            _ => {}
+         }
+         return pat;
+      }
+   }
+
+   impl VisitMut for VarHoister {
+      noop_visit_mut_type!();
+
+      // These have their own `var` scope.
+      fn visit_mut_function(&mut self, _: &mut Function) {}
+
+      fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+      fn visit_mut_constructor(&mut self, _: &mut Constructor) {}
+
+      fn visit_mut_getter_prop(&mut self, _: &mut GetterProp) {}
+
+      fn visit_mut_setter_prop(&mut self, _: &mut SetterProp) {}
+
+      fn visit_mut_static_block(&mut self, _: &mut StaticBlock) {}
+
+      fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+         stmt.visit_mut_children_with(self);
+
+         if let Stmt::Decl(Decl::Var(var_decl)) = stmt {
+            bail_if!(var_decl.kind != VarDeclKind::Var);
+            let span = var_decl.span;
+
+            *stmt = match self.hoist(var_decl) {
+               Some(expr) => Stmt::Expr(ExprStmt {
+                  span,
+                  expr,
+               }),
+               None => Stmt::Empty(EmptyStmt {
+                  span,
+               }),
+            };
+         }
+      }
+
+      fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+         stmts.visit_mut_children_with(self);
+         // Remove the declarations that had no initializers.
+         stmts.retain(|stmt| return !matches!(stmt, Stmt::Empty(_)));
+      }
+
+      fn visit_mut_for_stmt(&mut self, for_stmt: &mut ForStmt) {
+         for_stmt.visit_mut_children_with(self);
+
+         if let Some(VarDeclOrExpr::VarDecl(var_decl)) = &mut for_stmt.init {
+            bail_if!(var_decl.kind != VarDeclKind::Var);
+            for_stmt.init = self.hoist(var_decl).map(VarDeclOrExpr::Expr);
+         }
+      }
+
+      fn visit_mut_for_head(&mut self, for_head: &mut ForHead) {
+         if let ForHead::VarDecl(var_decl) = for_head {
+            bail_if!(var_decl.kind != VarDeclKind::Var);
+            // The head of a for-in/of loop has a single declarator.
+            let declarator = var_decl.decls.remove(0);
+            *for_head = ForHead::Pat(Box::new(self.hoist_pat(declarator.name)));
+         }
+      }
+   }
+
+   /// Replaces the syntax context of identifiers, which will be renamed by the
+   /// `hygiene` pass if they conflict with other bindings.
+   #[derive(Debug, Default)]
+   struct Renamer {
+      map: HashMap<Id, SyntaxContext>,
+   }
+
+   impl VisitMut for Renamer {
+      noop_visit_mut_type!();
+
+      visit_mut_obj_and_computed!();
+
+      fn visit_mut_ident(&mut self, ident: &mut Ident) {
+         if let Some(ctxt) = self.map.get(&ident.to_id()) {
+            ident.span = ident.span.with_ctxt(*ctxt);
+         }
+      }
+   }
+}
+
 /// Replaces all `return` statements with an assignment to the `temp_var`,
 /// followed by an optional `break` statement if we are inside a breakable
 /// statement (e.g. loop, switch, labeled statement).
@@ -566,8 +1618,9 @@ mod inline_transform {
       /// nested breakable statement. To overcome this we will need to add a
       /// label to the uppermost breakable statement to break to.
       return_label: Option<BlockLabel>,
-      /// Whether we should insert a break after the current statement.
-      insert_break: bool,
+      /// The span of the replaced return, if we should insert a break after
+      /// the current statement.
+      insert_break: Option<Span>,
       /// This is incremented when we enter a breakable statement and
       /// decremented when exited.
       ///
@@ -611,10 +1664,11 @@ mod inline_transform {
       fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
          stmt.visit_mut_children_with(self);
 
-         let replaced = replace_return(stmt, &self.temp_var.ident);
+         let replaced = replace_return(stmt, &self.temp_var);
 
-         if replaced {
-            self.insert_break = self.breakable_scope > 0;
+         if replaced.is_some() {
+            self.insert_break =
+               replaced.filter(|_| return self.breakable_scope > 0);
 
             if self.breakable_scope > 1 {
                // TODO: this may be optimized by adding `n` break statements
@@ -636,7 +1690,7 @@ mod inline_transform {
             if let Some(return_label) = self.return_label.take() {
                // We must now assign the required label to this statement.
                *stmt = Stmt::Labeled(LabeledStmt {
-                  span: DUMMY_SP,
+                  span: stmt.span(),
                   label: return_label.ident,
                   body: Box::new(stmt.take()),
                });
@@ -646,21 +1700,22 @@ mod inline_transform {
 
       #[save_state(insert_break)]
       fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
-         self.insert_break = false;
+         self.insert_break = None;
 
          if self.breakable_scope > 0 {
-            let mut insert_breaks_at: Vec<usize> = Vec::new();
+            let mut insert_breaks_at: Vec<(usize, Span)> = Vec::new();
 
             for (idx, stmt) in stmts.iter_mut().enumerate() {
                stmt.visit_mut_with(self);
 
-               if self.insert_break {
+               if let Some(span) = self.insert_break {
                   // Insert `break` after the current statement.
-                  insert_breaks_at.push(idx + 1);
+                  insert_breaks_at.push((idx + 1, span));
                }
             }
 
-            for (offset, at) in insert_breaks_at.iter().enumerate() {
+            for (offset, (at, span)) in insert_breaks_at.into_iter().enumerate()
+            {
                // The label is only required if we are inside a nested scope.
                let label = if self.breakable_scope > 1 {
                   self.return_label.as_ref().map(|l| return l.ident.clone())
@@ -671,7 +1726,7 @@ mod inline_transform {
                stmts.insert(
                   offset + at,
                   Stmt::Break(BreakStmt {
-                     span: DUMMY_SP,
+                     span,
                      label,
                   }),
                );
@@ -698,8 +1753,14 @@ mod wrapped_transform {
    #[derive(Debug, Default)]
    pub struct Visitor {
       pub temp_var: LazyVar,
+      /// The span of the block, used for the wrapper labeled statement. Not
+      /// the span of the statements, whose leading comments would otherwise
+      /// be placed before the label.
+      pub span: Span,
       block_label: BlockLabel,
-      insert_break: bool,
+      /// The span of the replaced return, if we should insert a break after
+      /// the current statement.
+      insert_break: Option<Span>,
       depth: usize,
    }
 
@@ -709,31 +1770,30 @@ mod wrapped_transform {
       fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
          stmt.visit_mut_children_with(self);
 
-         let replaced = replace_return(stmt, &self.temp_var.ident);
-         self.insert_break = replaced;
+         self.insert_break = replace_return(stmt, &self.temp_var);
       }
 
       #[save_state(insert_break)]
       fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
          self.depth += 1;
-         self.insert_break = false;
+         self.insert_break = None;
 
-         let mut insert_breaks_at: Vec<usize> = Vec::new();
+         let mut insert_breaks_at: Vec<(usize, Span)> = Vec::new();
 
          for (idx, stmt) in stmts.iter_mut().enumerate() {
             stmt.visit_mut_with(self);
 
-            if self.insert_break {
+            if let Some(span) = self.insert_break {
                // Insert `break` after the current statement.
-               insert_breaks_at.push(idx + 1);
+               insert_breaks_at.push((idx + 1, span));
             }
          }
 
-         for (offset, at) in insert_breaks_at.iter().enumerate() {
+         for (offset, (at, span)) in insert_breaks_at.into_iter().enumerate() {
             stmts.insert(
                offset + at,
                Stmt::Break(BreakStmt {
-                  span: DUMMY_SP,
+                  span,
                   label: Some(self.block_label.ident.clone()),
                }),
             );
@@ -748,7 +1808,7 @@ mod wrapped_transform {
             stmts.insert(
                0,
                Stmt::Labeled(LabeledStmt {
-                  span: DUMMY_SP,
+                  span: self.span,
                   label: self.block_label.ident.clone(),
                   body: Box::new(Stmt::Block(BlockStmt {
                      span: DUMMY_SP,
@@ -765,34 +1825,177 @@ mod wrapped_transform {
    }
 }
 
-/// Replaces a `return` statement with an assignment to an identifier.
+/// Collapses the statements of a transformed block when the temp variable is
+/// only assigned once, by the final statement. The assigned expression is then
+/// the value of the block and the temp variable is no longer needed.
 ///
-/// Returns `true` if the statement was replaced.
-fn replace_return<'a>(stmt: &'a mut Stmt, assign_to: &'a Ident) -> bool {
+/// ```js
+/// const val = block(() => {
+///    log();
+///    return 1;
+/// });
+/// // is transformed to:
+/// log();
+/// const val = 1;
+/// ```
+mod simplify {
+   use super::*;
+   use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+   pub fn collapse(
+      stmts: &mut Vec<Stmt>,
+      temp_var: &LazyVar,
+      comments: Option<&dyn Comments>,
+   ) -> Option<Box<Expr>> {
+      let mut v = Visitor {
+         id: temp_var.ident.to_id(),
+         refs: 0,
+      };
+      stmts.visit_with(&mut v);
+      bail_if!(v.refs != 1, None);
+
+      // The only reference must be the final `temp_var = <expr>;`.
+      if let Some(Stmt::Expr(ExprStmt {
+         expr,
+         ..
+      })) = stmts.last_mut()
+      {
+         if let Expr::Assign(AssignExpr {
+            op: op!("="),
+            left,
+            right,
+            ..
+         }) = &mut **expr
+         {
+            if left.as_ident().is_some_and(|i| return i.to_id() == v.id) {
+               let value = right.take();
+               if let Some(stmt) = stmts.pop() {
+                  utils::relocate_comments(comments, &stmt, value.span());
+               }
+               return Some(value);
+            }
+         }
+      }
+      return None;
+   }
+
+   /// Counts the references to the temp variable.
+   struct Visitor {
+      id: Id,
+      refs: usize,
+   }
+
+   impl Visit for Visitor {
+      noop_visit_type!();
+
+      fn visit_ident(&mut self, ident: &Ident) {
+         if ident.to_id() == self.id {
+            self.refs += 1;
+         }
+      }
+   }
+}
+
+/// A node of a statement list, which may be a variable declaration.
+trait StmtLike: Sized {
+   fn as_mut_stmt(&mut self) -> Option<&mut Stmt>;
+
+   fn from_stmt(stmt: Stmt) -> Self;
+
+   fn as_mut_var_decl(&mut self) -> Option<&mut VarDecl>;
+
+   /// Creates a node of the same shape as `self` with `var_decl` replacing
+   /// the declaration.
+   fn with_var_decl(&self, var_decl: VarDecl) -> Self;
+}
+
+impl StmtLike for Stmt {
+   fn as_mut_stmt(&mut self) -> Option<&mut Stmt> {
+      return Some(self);
+   }
+
+   fn from_stmt(stmt: Stmt) -> Self {
+      return stmt;
+   }
+
+   fn as_mut_var_decl(&mut self) -> Option<&mut VarDecl> {
+      return self.as_mut_decl()?.as_mut_var().map(|v| return &mut **v);
+   }
+
+   fn with_var_decl(&self, var_decl: VarDecl) -> Self {
+      return Stmt::Decl(Decl::Var(Box::new(var_decl)));
+   }
+}
+
+impl StmtLike for ModuleItem {
+   fn as_mut_stmt(&mut self) -> Option<&mut Stmt> {
+      return match self {
+         ModuleItem::Stmt(stmt) => Some(stmt),
+         ModuleItem::ModuleDecl(_) => None,
+      };
+   }
+
+   fn from_stmt(stmt: Stmt) -> Self {
+      return ModuleItem::Stmt(stmt);
+   }
+
+   fn as_mut_var_decl(&mut self) -> Option<&mut VarDecl> {
+      return match self {
+         ModuleItem::Stmt(stmt) => stmt.as_mut_var_decl(),
+         ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Var(var_decl),
+            ..
+         })) => Some(&mut **var_decl),
+         _ => None,
+      };
+   }
+
+   fn with_var_decl(&self, var_decl: VarDecl) -> Self {
+      return match self {
+         ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+               span: export_decl.span,
+               decl: Decl::Var(Box::new(var_decl)),
+            }))
+         }
+         _ => ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(var_decl)))),
+      };
+   }
+}
+
+/// Replaces a `return` statement with an assignment to an identifier. The
+/// assignment keeps the span of the `return` statement.
+///
+/// Returns the span of the statement if it was replaced.
+fn replace_return(stmt: &mut Stmt, assign_to: &LazyVar) -> Option<Span> {
    if let Stmt::Return(ReturnStmt {
+      span,
       arg,
-      ..
    }) = stmt
    {
+      let span = *span;
       let expr = if let Some(arg) = arg {
          // `return <arg>;` => `<arg>`
-         arg.clone()
+         arg.take()
       } else {
          // `return;` => `undefined`
-         undefined(DUMMY_SP)
+         undefined(span)
       };
       // `temp_var = <expr>;`
-      let assignment = Box::new(
-         expr.make_assign_to(op!("="), assign_to.clone().as_pat_or_expr()),
-      );
+      let assignment = Box::new(Expr::Assign(AssignExpr {
+         span,
+         op: op!("="),
+         left: assign_to.ident_at(span).as_pat_or_expr(),
+         right: expr,
+      }));
 
       *stmt = Stmt::Expr(ExprStmt {
-         span: DUMMY_SP,
+         span,
          expr: assignment,
       });
-      return true;
+      return Some(span);
    }
-   return false;
+   return None;
 }
 
 fn visit_switch_stmt<T: VisitMut>(
@@ -0,0 +1,137 @@
+use super::*;
+
+impl PluginConfig {
+   /// Whether the file is excluded from the transform by the `include`,
+   /// `exclude` and `excludeNodeModules` options. Relative paths and
+   /// patterns are resolved against `cwd`, when it is known.
+   pub fn is_excluded(&self, filename: &str, cwd: Option<&str>) -> bool {
+      let path = normalize_path(filename, cwd);
+      let segments: Vec<&str> = path.split('/').collect();
+
+      if self.exclude_node_modules && segments.contains(&"node_modules") {
+         return true;
+      }
+      let is_match = |patterns: &[String]| {
+         return patterns.iter().any(|pattern| {
+            let pattern = normalize_pattern(pattern, cwd);
+            let pattern: Vec<&str> = pattern.split('/').collect();
+            return match_segments(&pattern, &segments);
+         });
+      };
+
+      if !self.include.is_empty() && !is_match(&self.include) {
+         return true;
+      }
+      return is_match(&self.exclude);
+   }
+}
+
+/// Converts the path to an absolute path when `cwd` is known, using `/` as
+/// the separator and without `.` or `..` segments.
+fn normalize_path(path: &str, cwd: Option<&str>) -> String {
+   let path = path.replace('\\', "/");
+   let is_absolute = path.starts_with('/') || path.get(1..2) == Some(":");
+
+   let path = match cwd {
+      Some(cwd) if !is_absolute => {
+         format!(
+            "{}/{path}",
+            cwd.replace('\\', "/").trim_end_matches('/')
+         )
+      }
+      _ => path,
+   };
+
+   let mut segments: Vec<&str> = Vec::new();
+   for (i, segment) in path.split('/').enumerate() {
+      match segment {
+         // The empty first segment of an absolute path is kept.
+         "" if i > 0 => {}
+         "." => {}
+         ".." if segments.last().map_or(false, |s| return *s != "..") => {
+            segments.pop();
+         }
+         _ => segments.push(segment),
+      }
+   }
+   return segments.join("/");
+}
+
+/// A pattern whose last segment has neither a wildcard nor a file extension
+/// refers to a directory, so it matches every file within it, the same as
+/// `include` and `exclude` in `tsconfig.json`.
+fn normalize_pattern(pattern: &str, cwd: Option<&str>) -> String {
+   let pattern = normalize_path(pattern, cwd);
+   let last = pattern.rsplit('/').next().unwrap_or_default();
+
+   let has_wildcard = last.contains(['*', '?']);
+   let has_extension = last.rfind('.').map_or(false, |i| return i > 0);
+
+   if has_wildcard || has_extension {
+      return pattern;
+   }
+   return format!("{pattern}/**");
+}
+
+/// Matches the segments of a path against the segments of a glob pattern.
+///
+/// `*` matches any characters and `?` matches a single character within a
+/// segment, `**` matches any number of segments. Like `tsconfig.json`,
+/// wildcards do not match files or directories starting with a `.`.
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+   let (first, pattern) = unwrap_or!(pattern.split_first(), {
+      return path.is_empty();
+   });
+
+   if *first == "**" {
+      for i in 0..=path.len() {
+         if match_segments(pattern, &path[i..]) {
+            return true;
+         }
+         if path.get(i).map_or(false, |s| return s.starts_with('.')) {
+            return false;
+         }
+      }
+      return false;
+   }
+
+   return match path.split_first() {
+      Some((segment, path)) => {
+         match_segment(first, segment) && match_segments(pattern, path)
+      }
+      None => false,
+   };
+}
+
+fn match_segment(pattern: &str, segment: &str) -> bool {
+   if segment.starts_with('.') && !pattern.starts_with('.') {
+      return pattern == segment;
+   }
+   let pattern: Vec<char> = pattern.chars().collect();
+   let segment: Vec<char> = segment.chars().collect();
+
+   // Index of the last `*` in the pattern and the position in the segment it
+   // was matched at, used to backtrack when the rest does not match.
+   let mut star: Option<(usize, usize)> = None;
+   let (mut p, mut s) = (0, 0);
+
+   while s < segment.len() {
+      match pattern.get(p) {
+         Some('*') => {
+            star = Some((p, s));
+            p += 1;
+         }
+         Some(c) if *c == '?' || *c == segment[s] => {
+            p += 1;
+            s += 1;
+         }
+         _ => {
+            let (star_p, star_s) = unwrap_or!(star, return false);
+            p = star_p + 1;
+            s = star_s + 1;
+            star = Some((star_p, star_s + 1));
+         }
+      }
+   }
+   return pattern[p..].iter().all(|c| return *c == '*');
+}
@@ -0,0 +1,61 @@
+use serde::{
+   de::{self, DeserializeOwned, Visitor},
+   forward_to_deserialize_any, Deserializer,
+};
+
+/// Returns the names of the fields of a struct as they appear in the config.
+pub fn field_names<T: DeserializeOwned>() -> &'static [&'static str] {
+   return names::<T>();
+}
+
+/// Returns the names of the variants of an enum as they appear in the config.
+pub fn variant_names<T: DeserializeOwned>() -> &'static [&'static str] {
+   return names::<T>();
+}
+
+/// Deserializes `T` from a deserializer that records the names of the fields
+/// or variants it is asked for, which serde passes after renaming them.
+fn names<T: DeserializeOwned>() -> &'static [&'static str] {
+   struct Names<'a>(&'a mut &'static [&'static str]);
+
+   impl<'de> Deserializer<'de> for Names<'_> {
+      type Error = de::value::Error;
+
+      forward_to_deserialize_any! {
+         bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+         bytes byte_buf option unit unit_struct newtype_struct seq tuple
+         tuple_struct map identifier ignored_any
+      }
+
+      fn deserialize_any<V: Visitor<'de>>(
+         self,
+         _: V,
+      ) -> Result<V::Value, Self::Error> {
+         return Err(de::Error::custom("expected a struct or an enum"));
+      }
+
+      fn deserialize_struct<V: Visitor<'de>>(
+         self,
+         _: &'static str,
+         fields: &'static [&'static str],
+         _: V,
+      ) -> Result<V::Value, Self::Error> {
+         *self.0 = fields;
+         return Err(de::Error::custom("names recorded"));
+      }
+
+      fn deserialize_enum<V: Visitor<'de>>(
+         self,
+         _: &'static str,
+         variants: &'static [&'static str],
+         _: V,
+      ) -> Result<V::Value, Self::Error> {
+         *self.0 = variants;
+         return Err(de::Error::custom("names recorded"));
+      }
+   }
+
+   let mut names: &'static [&'static str] = &[];
+   let _ = T::deserialize(Names(&mut names));
+   return names;
+}
@@ -0,0 +1,820 @@
+use serde::Deserialize;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::HashMap;
+use swc_atoms::JsWord;
+use swc_ecma_ast::*;
+
+use super::*;
+use diagnostics::DiagnosticCode;
+use lazy_init_pkg::{ExportedFn, ExportedFnKind, LzMethod, LzMethodKind};
+pub use schema::config_schema;
+pub use validate::parse_config;
+
+mod files;
+mod introspect;
+mod schema;
+mod validate;
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(
+   default,
+   deny_unknown_fields,
+   rename_all = "camelCase"
+)]
+pub struct PluginConfig {
+   /// The operator used to check whether a lazy variable has been
+   /// initialized.
+   ///
+   /// `0` - Nullish Coalescing (`??`)
+   /// ```ts
+   /// lzVar ?? (lzVar = lz({}));
+   /// ```
+   ///
+   /// `1` - Logical OR (`||`)
+   /// ```ts
+   /// lzVar || (lzVar = lz({}));
+   /// ```
+   ///
+   /// Defaults to `0` (`??`).
+   pub operator: LazyOperator,
+
+   /// Configure custom functions that will be transformed in the same way as
+   /// those exported from `lazy-init`.
+   ///
+   /// Each entry is a `[source, fns]` tuple, where `source` is the import
+   /// source and `fns` lists the exported functions. Functions in `lz` are
+   /// equivalent to `lz` and map each `lz` method (`fn`, `async` and `block`)
+   /// to the names of their own methods. Functions in `block` are equivalent
+   /// to `block`. See `schema.json` for the full schema.
+   ///
+   /// ### Example
+   /// ```ts
+   /// // Config
+   /// {
+   ///    customFns: [
+   ///       ["@myorg/lazy", {
+   ///          lz: [
+   ///             ["customLazy", {
+   ///                fn: ["func", "call"],
+   ///                async: ["ac"],
+   ///                block: ["scoped"],
+   ///             }],
+   ///          ],
+   ///          block: ["bk", "wrapper"],
+   ///       }],
+   ///    ],
+   /// }
+   /// // Custom function use with equivalent lazy-init function
+   /// import { lz, block } from "lazy-init";
+   /// import { customLazy, bk, wrapper } from "@myorg/lazy";
+   ///
+   /// lz(...)
+   /// customLazy(...)
+   ///
+   /// lz.fn(...)
+   /// customLazy.func(...)
+   /// customLazy.call(...)
+   ///
+   /// lz.async(...)
+   /// customLazy.ac(...)
+   ///
+   /// lz.block(...)
+   /// customLazy.scoped(...)
+   ///
+   /// block(...)
+   /// bk(...)
+   /// wrapper(...)
+   /// ```
+   pub custom_fns: Vec<(JsWord, CustomLazyFns)>,
+
+   /// Do not check for imports from the `lazy-init` library.
+   ///
+   /// Set to `true` if you are solely using a custom lazy function from a
+   /// different library.
+   pub ignore_lazy_library: bool,
+
+   /// Disable this plugin.
+   ///
+   /// A single file can be opted out using a `/* lazy-init-disable */`
+   /// comment at the top of the file or a `"use no lazy"` directive, and a
+   /// single call using a `/* lazy-init-ignore */` comment before it.
+   pub disable: bool,
+
+   /// Glob patterns of the files to transform, which follow the same
+   /// behaviour as `include` in `tsconfig.json`. Patterns are relative to
+   /// the working directory, and a pattern without a wildcard or file
+   /// extension matches every file within that directory.
+   ///
+   /// ### Example
+   /// ```ts
+   /// { include: ["src"], exclude: ["src/**/*.test.ts"] }
+   /// ```
+   ///
+   /// Defaults to all files.
+   pub include: Vec<String>,
+
+   /// Glob patterns of the files to skip, which follow the same behaviour
+   /// as `exclude` in `tsconfig.json`. See `include`.
+   pub exclude: Vec<String>,
+
+   /// Skip files within a `node_modules` directory.
+   ///
+   /// Defaults to `true`.
+   pub exclude_node_modules: bool,
+
+   /// Enable or disable hoisting for each kind of `lz` call. A call whose
+   /// kind is disabled is left as a runtime call.
+   ///
+   /// ### Example
+   /// ```ts
+   /// // Config
+   /// { hoist: { async: false } }
+   /// // `lz()` and `lz.fn()` are hoisted, `lz.async()` is only passed its
+   /// // key.
+   /// ```
+   ///
+   /// Defaults to all kinds enabled.
+   pub hoist: HoistConfig,
+
+   /// Enable or disable the transforms of `lz.block()` calls. A block that
+   /// requires a disabled transform is left as a runtime call.
+   ///
+   /// `enabled` - Whether blocks are transformed at all.
+   ///
+   /// `inline` - Whether blocks can be inlined. When disabled, blocks are
+   /// wrapped in a labeled statement instead.
+   ///
+   /// `wrapped` - Whether blocks can be wrapped in a labeled statement. When
+   /// disabled, only blocks that can be inlined are transformed.
+   ///
+   /// Defaults to all enabled.
+   pub block: BlockConfig,
+
+   /// Whether to compress the code inlined from `lz.block()` calls. Branches
+   /// that only produce the value of the block are simplified, `if`/`else`
+   /// chains and `switch` statements over constants become conditional
+   /// expressions. No other minification is performed.
+   ///
+   /// Defaults to `true`.
+   pub compress_blocks: bool,
+
+   /// How to handle a `lz.block()` call that cannot be inlined, e.g. when it
+   /// is not used to initialize a variable declaration.
+   ///
+   /// `"error"` - Report an error, which fails the build.
+   ///
+   /// `"warn"` - Report a warning and call the function passed to the block
+   /// instead.
+   /// ```ts
+   /// foo(block(() => { ... }));
+   /// // is transformed to:
+   /// foo((() => { ... })());
+   /// ```
+   ///
+   /// `"silent"` - Same as `"warn"`, without reporting a warning.
+   ///
+   /// Defaults to `"error"`.
+   pub block_fallback: BlockFallback,
+
+   /// The transform used to inline the code of `lz.block()` calls whose
+   /// paths all return.
+   ///
+   /// `"auto"` - Uses the inline transform, unless wrapping the code in a
+   /// labeled statement results in smaller and flatter code, e.g. for a long
+   /// sequence of guard clauses.
+   ///
+   /// `"inline"` - Always uses the inline transform.
+   ///
+   /// `"wrapped"` - Always wraps the code in a labeled statement, the same as
+   /// placing a `/* lazy-init-force-wrapped */` comment before each call.
+   ///
+   /// Defaults to `"auto"`.
+   pub block_transform: BlockTransform,
+
+   /// Report a warning explaining why a `lz.block()` call could not be
+   /// inlined and is wrapped in a labeled statement instead. Useful for
+   /// restructuring blocks in hot paths to get the cheaper inline form.
+   ///
+   /// Blocks that are wrapped due to the `blockTransform` option, or because
+   /// the wrapped form is cheaper, are not reported.
+   ///
+   /// Defaults to `false`.
+   pub report_wrapped: bool,
+
+   /// Change the severity of diagnostics, which are referred to by their
+   /// code or name.
+   ///
+   /// `"off"` - The diagnostic is not reported.
+   ///
+   /// `"warn"` - The diagnostic is reported as a warning.
+   ///
+   /// `"error"` - The diagnostic is reported as an error, which fails the
+   /// build.
+   ///
+   /// ### Example
+   /// ```ts
+   /// {
+   ///    diagnostics: {
+   ///       "LZ0012": "error",
+   ///       "hoist-captures-local": "off",
+   ///    },
+   /// }
+   /// ```
+   pub diagnostics: HashMap<DiagnosticCode, DiagnosticLevel>,
+}
+
+impl Default for PluginConfig {
+   fn default() -> Self {
+      return Self {
+         operator: LazyOperator::default(),
+         custom_fns: Vec::new(),
+         ignore_lazy_library: false,
+         disable: false,
+         include: Vec::new(),
+         exclude: Vec::new(),
+         exclude_node_modules: true,
+         hoist: HoistConfig::default(),
+         block: BlockConfig::default(),
+         compress_blocks: true,
+         block_fallback: BlockFallback::default(),
+         block_transform: BlockTransform::default(),
+         report_wrapped: false,
+         diagnostics: HashMap::new(),
+      };
+   }
+}
+
+impl PluginConfig {
+   /// Whether calls of this kind are transformed, see the `hoist` and
+   /// `block` options.
+   pub fn is_enabled(&self, kind: LzMethodKind) -> bool {
+      return match kind {
+         LzMethodKind::Obj => self.hoist.obj,
+         LzMethodKind::Fn => self.hoist.func,
+         LzMethodKind::Async => self.hoist.asynchronous,
+         LzMethodKind::Block => {
+            self.block.enabled && (self.block.inline || self.block.wrapped)
+         }
+      };
+   }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct HoistConfig {
+   /// `lz()`
+   pub obj: bool,
+   /// `lz.fn()`
+   #[serde(rename = "fn")]
+   pub func: bool,
+   /// `lz.async()`
+   ///
+   /// The runtime requires the unique key that is passed to each call, so
+   /// when disabled, calls are not hoisted but are still passed their key.
+   #[serde(rename = "async")]
+   pub asynchronous: bool,
+}
+
+impl Default for HoistConfig {
+   fn default() -> Self {
+      return Self {
+         obj: true,
+         func: true,
+         asynchronous: true,
+      };
+   }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct BlockConfig {
+   pub enabled: bool,
+   pub inline: bool,
+   pub wrapped: bool,
+}
+
+impl Default for BlockConfig {
+   fn default() -> Self {
+      return Self {
+         enabled: true,
+         inline: true,
+         wrapped: true,
+      };
+   }
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockFallback {
+   #[default]
+   Error,
+   Warn,
+   Silent,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockTransform {
+   #[default]
+   Auto,
+   Inline,
+   Wrapped,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticLevel {
+   Off,
+   Warn,
+   Error,
+}
+
+#[derive(
+   Debug, Default, Clone, Deserialize_repr, Serialize_repr, PartialEq,
+)]
+#[repr(u8)]
+pub enum LazyOperator {
+   #[default]
+   /// Nullish Coalescing (`??`)
+   NullishCoalescing = 0,
+   /// Logical OR (`||`)
+   LogicalOr = 1,
+}
+
+impl LazyOperator {
+   pub fn to_bin_op(&self) -> BinaryOp {
+      return match self {
+         LazyOperator::NullishCoalescing => op!("??"),
+         LazyOperator::LogicalOr => op!("||"),
+      };
+   }
+}
+
+#[derive(Debug, Default, Clone, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CustomLazyFns {
+   #[serde(default)]
+   pub lz: Vec<(JsWord, CustomLzMethodMap)>,
+
+   #[serde(default)]
+   pub block: Vec<JsWord>,
+}
+
+impl CustomLazyFns {
+   pub fn find_fn(&self, name: &JsWord) -> Option<ExportedFn> {
+      return self
+         .find_lz_fn(name)
+         .or_else(|| return self.find_block_fn(name));
+   }
+
+   fn find_lz_fn(&self, name: &JsWord) -> Option<ExportedFn> {
+      let (_, method_map) = self.lz.iter().find(|(n, _)| {
+         return n == name;
+      })?;
+
+      return Some(ExportedFn {
+         kind: ExportedFnKind::Hoist,
+         methods: Some(method_map.get_lz_methods()),
+      });
+   }
+
+   fn find_block_fn(&self, name: &JsWord) -> Option<ExportedFn> {
+      let found = self.block.iter().any(|n| {
+         return n == name;
+      });
+
+      if found {
+         return Some(ExportedFn {
+            kind: ExportedFnKind::Block,
+            methods: None,
+         });
+      }
+      return None;
+   }
+}
+
+#[derive(Debug, Default, Clone, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CustomLzMethodMap {
+   #[serde(default, rename = "fn")]
+   pub func: Vec<JsWord>,
+
+   #[serde(default, rename = "async")]
+   pub asynchronous: Vec<JsWord>,
+
+   #[serde(default)]
+   pub block: Vec<JsWord>,
+}
+
+impl CustomLzMethodMap {
+   pub fn get_lz_methods(&self) -> Vec<LzMethod> {
+      let mut methods: Vec<LzMethod> = Vec::new();
+
+      for method in &self.block {
+         methods.push(LzMethod {
+            sym: method.clone(),
+            kind: LzMethodKind::Block,
+         });
+      }
+      for method in &self.func {
+         methods.push(LzMethod {
+            sym: method.clone(),
+            kind: LzMethodKind::Fn,
+         });
+      }
+      for method in &self.asynchronous {
+         methods.push(LzMethod {
+            sym: method.clone(),
+            kind: LzMethodKind::Async,
+         });
+      }
+      return methods;
+   }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[cfg(test)]
+mod config_parse_test {
+   use super::*;
+   use serde_json::{json, Value};
+
+   #[test]
+   fn a() {
+      let config_json = json!({
+         "operator": 1,
+         "customFns": [
+            ["@myorg/lazy", {
+               "lz": [["customLazy", {
+                  "fn": ["func", "call"],
+                  "async": ["ac"],
+                  "block": ["scoped"],
+               }]],
+               "block": ["bk", "wrapper"],
+            }],
+         ],
+      });
+
+      let config: PluginConfig =
+         serde_json::from_value(config_json).expect("failed to parse config");
+
+      assert_eq!(config.operator.to_bin_op(), op!("||"));
+
+      let expected_custom_fns: Vec<(JsWord, CustomLazyFns)> =
+         vec![("@myorg/lazy".into(), CustomLazyFns {
+            lz: vec![
+               ("customLazy".into(), CustomLzMethodMap {
+                  func: vec!["func".into(), "call".into()],
+                  asynchronous: vec!["ac".into()],
+                  block: vec!["scoped".into()],
+               }),
+            ],
+            block: vec!["bk".into(), "wrapper".into()],
+         })];
+
+      // pub custom_fns: Vec<(JsWord, CustomLazyFns)>
+      assert_eq!(config.custom_fns, expected_custom_fns);
+      assert!(config.compress_blocks);
+      assert_eq!(config.block_fallback, BlockFallback::Error);
+      assert_eq!(config.block_transform, BlockTransform::Auto);
+      assert!(!config.report_wrapped);
+      assert!(config.include.is_empty() && config.exclude.is_empty());
+      assert!(config.exclude_node_modules);
+      assert_eq!(config.hoist, HoistConfig::default());
+      assert_eq!(config.block, BlockConfig::default());
+      assert!(config.diagnostics.is_empty());
+   }
+
+   #[test]
+   fn diagnostics() {
+      let config: PluginConfig = serde_json::from_value(json!({
+         "diagnostics": {
+            "LZ0001": "warn",
+            "unreachable-code": "error",
+            "hoist-primitive": "off",
+         }
+      }))
+      .expect("Failed to parse config.");
+
+      assert_eq!(
+         config.diagnostics,
+         [
+            (
+               DiagnosticCode::BlockMissingReturn,
+               DiagnosticLevel::Warn
+            ),
+            (
+               DiagnosticCode::UnreachableCode,
+               DiagnosticLevel::Error
+            ),
+            (
+               DiagnosticCode::HoistPrimitive,
+               DiagnosticLevel::Off
+            ),
+         ]
+         .into_iter()
+         .collect::<HashMap<_, _>>()
+      );
+
+      let err = serde_json::from_value::<PluginConfig>(json!({
+         "diagnostics": { "LZ9999": "warn" }
+      }))
+      .expect_err("Expected an unknown diagnostic to fail.");
+
+      assert!(
+         err.to_string().contains("unknown variant `LZ9999`"),
+         "{err}"
+      );
+   }
+
+   #[test]
+   fn errors() {
+      for (config_json, expected) in [
+         (json!({ "customFn": [] }), vec![(
+            "Invalid plugin config at `customFn`: unknown option `customFn`.",
+            Some("Did you mean `customFns`?"),
+         )]),
+         (json!({ "operator": "??" }), vec![(
+            "Invalid plugin config at `operator`: invalid type: string \
+             \"??\", expected u8.",
+            Some("Accepted values are `0` (`??`) and `1` (`||`)."),
+         )]),
+         (
+            json!({
+               "customFns": [["@myorg/lazy", { "lz": [["customLazy", {
+                  "fns": ["func"],
+                  "async": "ac",
+               }]] }]],
+            }),
+            vec![
+               (
+                  "Invalid plugin config at `customFns[0][1].lz[0][1].async`: \
+                   invalid type: string \"ac\", expected a sequence.",
+                  None,
+               ),
+               (
+                  "Invalid plugin config at `customFns[0][1].lz[0][1].fns`: \
+                   unknown option `fns`.",
+                  Some("Did you mean `fn`?"),
+               ),
+            ],
+         ),
+         (
+            json!({
+               "blockFallback": "warning",
+               "diagnostics": { "unreachable": "off" },
+            }),
+            vec![
+               (
+                  "Invalid plugin config at `blockFallback`: unknown variant \
+                   `warning`, expected one of `error`, `warn`, `silent`.",
+                  None,
+               ),
+               (
+                  "Invalid plugin config at `diagnostics.unreachable`: \
+                   unknown diagnostic `unreachable`.",
+                  Some("Did you mean `unreachable-code`?"),
+               ),
+            ],
+         ),
+         (
+            json!({ "block": { "inlined": false }, "hoist": { "fn": 0 } }),
+            vec![
+               (
+                  "Invalid plugin config at `block.inlined`: unknown option \
+                   `inlined`.",
+                  Some("Did you mean `inline`?"),
+               ),
+               (
+                  "Invalid plugin config at `hoist.fn`: invalid type: integer \
+                   `0`, expected a boolean.",
+                  None,
+               ),
+            ],
+         ),
+         (json!({ "zzz": true }), vec![(
+            "Invalid plugin config at `zzz`: unknown option `zzz`.",
+            Some(
+               "Expected one of `operator`, `customFns`, `ignoreLazyLibrary`, \
+                `disable`, `include`, `exclude`, `excludeNodeModules`, \
+                `hoist`, `block`, `compressBlocks`, `blockFallback`, \
+                `blockTransform`, `reportWrapped`, `diagnostics`.",
+            ),
+         )]),
+      ] {
+         let diagnostics = parse_config(&config_json.to_string())
+            .expect_err("Expected the config to be invalid.");
+         let actual: Vec<_> = diagnostics
+            .iter()
+            .map(|d| return (d.message.as_str(), d.help.as_deref()))
+            .collect();
+
+         assert_eq!(actual, expected, "Config: {config_json}");
+      }
+
+      let diagnostics = parse_config("{ \"operator\": 1,, }")
+         .expect_err("Expected the config to be invalid.");
+      assert_eq!(
+         diagnostics[0].message,
+         "Invalid plugin config: key must be a string at line 1 column 17."
+      );
+
+      assert_eq!(
+         parse_config("{}").expect("Failed to parse config."),
+         PluginConfig::default()
+      );
+   }
+
+   #[test]
+   fn schema() {
+      let path =
+         std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("schema.json");
+      // Indented the same as the other JSON files formatted by dprint.
+      let mut generated = Vec::new();
+      let formatter = serde_json::ser::PrettyFormatter::with_indent(b"   ");
+      let mut serializer =
+         serde_json::Serializer::with_formatter(&mut generated, formatter);
+      serde::Serialize::serialize(&config_schema(), &mut serializer)
+         .expect("Failed to serialize the schema.");
+      let generated = String::from_utf8(generated)
+         .expect("Failed to serialize the schema.") +
+         "\n";
+
+      if std::env::var("UPDATE").is_ok() {
+         std::fs::write(&path, generated).expect("Failed to write schema.");
+         return;
+      }
+      let existing =
+         std::fs::read_to_string(&path).expect("Failed to read schema.");
+
+      assert!(
+         existing == generated,
+         "`schema.json` is out of date, run the tests with `UPDATE=1`."
+      );
+   }
+
+   #[test]
+   fn schema_round_trip() {
+      let schema = config_schema();
+      let parse = |config_json: Value| {
+         return parse_config(&config_json.to_string())
+            .map(|config| return format!("{config:?}"))
+            .map_err(|diagnostics| return format!("{diagnostics:?}"));
+      };
+      let default = parse(json!({})).expect("Failed to parse config.");
+
+      let properties = schema["properties"]
+         .as_object()
+         .expect("Expected the schema to have properties.");
+
+      for (key, property) in properties {
+         // The default in the schema is the default of the option.
+         assert_eq!(
+            parse(json!({ key: property["default"] })).as_ref(),
+            Ok(&default),
+            "{key}"
+         );
+
+         let values = [&property["enum"], &property["examples"]];
+         for value in values.iter().filter_map(|v| return v.as_array()) {
+            for value in value {
+               assert!(
+                  parse(json!({ key: value })).is_ok(),
+                  "{key}: {value}"
+               );
+            }
+         }
+      }
+
+      let diagnostics = &properties["diagnostics"];
+      for key in diagnostics["propertyNames"]["enum"]
+         .as_array()
+         .expect("Expected the diagnostic keys.")
+      {
+         for level in diagnostics["additionalProperties"]["enum"]
+            .as_array()
+            .expect("Expected the diagnostic levels.")
+         {
+            let key = key.as_str().expect("Expected a string.");
+            assert!(
+               parse(json!({ "diagnostics": { key: level } })).is_ok(),
+               "{key}: {level}"
+            );
+         }
+      }
+
+      // Values outside of the schema are rejected.
+      assert!(parse(json!({ "operator": 2 })).is_err());
+      assert!(parse(json!({ "blockTransform": "none" })).is_err());
+   }
+
+   #[test]
+   fn files() {
+      let config: PluginConfig = serde_json::from_value(json!({
+         "include": ["src", "lib/*.ts", "scripts/**/?.js"],
+         "exclude": ["src/**/*.test.ts", "./src/generated"],
+      }))
+      .expect("Failed to parse config.");
+      let cwd = Some("/project");
+
+      for (filename, expected) in [
+         ("/project/src/index.ts", false),
+         ("/project/src/a/b/c.tsx", false),
+         ("src/index.ts", false),
+         ("./src/../src/index.ts", false),
+         ("/project/src/index.test.ts", true),
+         ("/project/src/a/index.test.ts", true),
+         ("/project/src/generated/types.ts", true),
+         ("/project/src/.hidden/index.ts", true),
+         ("/project/lib/index.ts", false),
+         ("/project/lib/a/index.ts", true),
+         ("/project/lib/index.js", true),
+         ("/project/scripts/a.js", false),
+         ("/project/scripts/x/y/a.js", false),
+         ("/project/scripts/ab.js", true),
+         ("/project/test/index.ts", true),
+         ("/other/src/index.ts", true),
+         ("/project/src/node_modules/a/index.ts", true),
+      ] {
+         assert_eq!(
+            config.is_excluded(filename, cwd),
+            expected,
+            "{filename}"
+         );
+      }
+
+      // Windows paths.
+      let cwd = Some("C:\\project");
+      assert!(!config.is_excluded("C:\\project\\src\\index.ts", cwd));
+      assert!(config.is_excluded("C:\\project\\src\\a.test.ts", cwd));
+
+      let config = PluginConfig::default();
+      assert!(!config.is_excluded("/project/src/index.ts", None));
+      assert!(config.is_excluded("/project/node_modules/a/index.js", None));
+      assert!(config.is_excluded("node_modules/a/index.js", None));
+
+      let config: PluginConfig =
+         serde_json::from_value(json!({ "excludeNodeModules": false }))
+            .expect("Failed to parse config.");
+      assert!(!config.is_excluded("/project/node_modules/a/index.js", None));
+   }
+
+   #[test]
+   fn switches() {
+      let config: PluginConfig = serde_json::from_value(json!({
+         "hoist": { "async": false },
+         "block": { "inline": false },
+      }))
+      .expect("Failed to parse config.");
+
+      assert_eq!(config.hoist, HoistConfig {
+         obj: true,
+         func: true,
+         asynchronous: false,
+      });
+      assert_eq!(config.block, BlockConfig {
+         enabled: true,
+         inline: false,
+         wrapped: true,
+      });
+      assert!(config.is_enabled(LzMethodKind::Fn));
+      assert!(!config.is_enabled(LzMethodKind::Async));
+      assert!(config.is_enabled(LzMethodKind::Block));
+
+      let config: PluginConfig = serde_json::from_value(json!({
+         "block": { "inline": false, "wrapped": false },
+      }))
+      .expect("Failed to parse config.");
+
+      assert!(!config.is_enabled(LzMethodKind::Block));
+   }
+
+   #[test]
+   fn block_fallback() {
+      for (value, expected) in [
+         ("error", BlockFallback::Error),
+         ("warn", BlockFallback::Warn),
+         ("silent", BlockFallback::Silent),
+      ] {
+         let config: PluginConfig =
+            serde_json::from_value(json!({ "blockFallback": value }))
+               .expect("failed to parse config");
+
+         assert_eq!(config.block_fallback, expected);
+      }
+   }
+
+   #[test]
+   fn block_transform() {
+      for (value, expected) in [
+         ("auto", BlockTransform::Auto),
+         ("inline", BlockTransform::Inline),
+         ("wrapped", BlockTransform::Wrapped),
+      ] {
+         let config: PluginConfig =
+            serde_json::from_value(json!({ "blockTransform": value }))
+               .expect("failed to parse config");
+
+         assert_eq!(config.block_transform, expected);
+      }
+   }
+}
@@ -0,0 +1,243 @@
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+
+use super::*;
+use diagnostics::DiagnosticCode;
+use introspect::{field_names, variant_names};
+
+/// Returns the JSON Schema of the plugin config, which is used to validate
+/// the config and to provide autocompletion in editors. It is written to
+/// `schema.json` by the `config_parse_test::schema` test.
+///
+/// The fields and variants are read from the serde definitions, so an option
+/// without a schema fails the test instead of being silently left out.
+pub fn config_schema() -> Value {
+   return json!({
+      "$schema": "http://json-schema.org/draft-07/schema#",
+      "title": "swc-plugin-lazy-init",
+      "description": "The config of the lazy-init SWC plugin.",
+      "type": "object",
+      "properties": properties::<PluginConfig>(plugin_config_property),
+      "additionalProperties": false,
+      "definitions": {
+         "CustomLazyFns": {
+            "description": "The functions exported from a custom import \
+                            source.",
+            "type": "object",
+            "properties": properties::<CustomLazyFns>(custom_lazy_fns_property),
+            "additionalProperties": false,
+         },
+         "CustomLzMethodMap": {
+            "description": "The methods of a custom `lz` function, by the \
+                            `lz` method they are equivalent to.",
+            "type": "object",
+            "properties": properties::<CustomLzMethodMap>(|_| {
+               return string_array();
+            }),
+            "additionalProperties": false,
+         },
+      },
+   });
+}
+
+fn plugin_config_property(field: &str) -> Value {
+   return match field {
+      "operator" => json!({
+         "description": "The operator used to check whether a lazy variable \
+                         has been initialized. `0` - Nullish Coalescing \
+                         (`??`), `1` - Logical OR (`||`).",
+         "enum": [0, 1],
+         "default": 0,
+      }),
+      "customFns" => json!({
+         "description": "Custom functions that will be transformed in the \
+                         same way as those exported from `lazy-init`. Each \
+                         entry is an import source and the functions it \
+                         exports.",
+         "type": "array",
+         "items": named_entry(
+            "The import source, e.g. `@myorg/lazy`.",
+            &json!({ "$ref": "#/definitions/CustomLazyFns" }),
+         ),
+         "default": [],
+         "examples": [[
+            ["@myorg/lazy", {
+               "lz": [["customLazy", {
+                  "fn": ["func", "call"],
+                  "async": ["ac"],
+                  "block": ["scoped"],
+               }]],
+               "block": ["bk", "wrapper"],
+            }],
+         ]],
+      }),
+      "ignoreLazyLibrary" => json!({
+         "description": "Do not check for imports from the `lazy-init` \
+                         library.",
+         "type": "boolean",
+         "default": false,
+      }),
+      "disable" => json!({
+         "description": "Disable this plugin.",
+         "type": "boolean",
+         "default": false,
+      }),
+      "include" => json!({
+         "description": "Glob patterns of the files to transform, which \
+                         follow the same behaviour as `include` in \
+                         `tsconfig.json`.",
+         "type": "array",
+         "items": { "type": "string" },
+         "default": [],
+         "examples": [["src"]],
+      }),
+      "exclude" => json!({
+         "description": "Glob patterns of the files to skip, which follow \
+                         the same behaviour as `exclude` in `tsconfig.json`.",
+         "type": "array",
+         "items": { "type": "string" },
+         "default": [],
+         "examples": [["src/**/*.test.ts"]],
+      }),
+      "excludeNodeModules" => json!({
+         "description": "Skip files within a `node_modules` directory.",
+         "type": "boolean",
+         "default": true,
+      }),
+      "hoist" => json!({
+         "description": "Enable or disable hoisting for each kind of `lz` \
+                         call. A call whose kind is disabled is left as a \
+                         runtime call.",
+         "type": "object",
+         "properties": properties::<HoistConfig>(hoist_config_property),
+         "additionalProperties": false,
+         "default": { "obj": true, "fn": true, "async": true },
+      }),
+      "block" => json!({
+         "description": "Enable or disable the transforms of `lz.block()` \
+                         calls. A block that requires a disabled transform \
+                         is left as a runtime call.",
+         "type": "object",
+         "properties": properties::<BlockConfig>(block_config_property),
+         "additionalProperties": false,
+         "default": { "enabled": true, "inline": true, "wrapped": true },
+      }),
+      "compressBlocks" => json!({
+         "description": "Whether to compress the code inlined from \
+                         `lz.block()` calls.",
+         "type": "boolean",
+         "default": true,
+      }),
+      "blockFallback" => json!({
+         "description": "How to handle a `lz.block()` call that cannot be \
+                         inlined.",
+         "enum": variant_names::<BlockFallback>(),
+         "default": "error",
+      }),
+      "blockTransform" => json!({
+         "description": "The transform used to inline the code of \
+                         `lz.block()` calls whose paths all return.",
+         "enum": variant_names::<BlockTransform>(),
+         "default": "auto",
+      }),
+      "reportWrapped" => json!({
+         "description": "Report a warning explaining why a `lz.block()` \
+                         call could not be inlined.",
+         "type": "boolean",
+         "default": false,
+      }),
+      "diagnostics" => json!({
+         "description": "Change the severity of diagnostics, which are \
+                         referred to by their code or name.",
+         "type": "object",
+         "propertyNames": { "enum": DiagnosticCode::KEYS },
+         "additionalProperties": {
+            "enum": variant_names::<DiagnosticLevel>(),
+         },
+         "default": {},
+      }),
+      _ => unreachable!("The schema of `{field}` is missing."),
+   };
+}
+
+fn hoist_config_property(field: &str) -> Value {
+   return match field {
+      "obj" => switch("Hoist `lz()` calls."),
+      "fn" => switch("Hoist `lz.fn()` calls."),
+      "async" => switch(
+         "Hoist `lz.async()` calls. Calls are passed the unique key the \
+          runtime requires even when disabled.",
+      ),
+      _ => unreachable!("The schema of `{field}` is missing."),
+   };
+}
+
+fn block_config_property(field: &str) -> Value {
+   return match field {
+      "enabled" => switch("Whether blocks are transformed at all."),
+      "inline" => switch("Whether blocks can be inlined."),
+      "wrapped" => {
+         switch("Whether blocks can be wrapped in a labeled statement.")
+      }
+      _ => unreachable!("The schema of `{field}` is missing."),
+   };
+}
+
+fn custom_lazy_fns_property(field: &str) -> Value {
+   return match field {
+      "lz" => json!({
+         "description": "Functions that are equivalent to `lz`, with their \
+                         methods.",
+         "type": "array",
+         "items": named_entry(
+            "The name of the function.",
+            &json!({ "$ref": "#/definitions/CustomLzMethodMap" }),
+         ),
+      }),
+      "block" => json!({
+         "description": "Functions that are equivalent to `block`.",
+         "type": "array",
+         "items": { "type": "string" },
+      }),
+      _ => unreachable!("The schema of `{field}` is missing."),
+   };
+}
+
+/// Returns the schema of each field of a struct.
+fn properties<T: DeserializeOwned>(
+   property: impl Fn(&str) -> Value,
+) -> Map<String, Value> {
+   return field_names::<T>()
+      .iter()
+      .map(|field| return ((*field).to_owned(), property(field)))
+      .collect();
+}
+
+/// A `[name, value]` tuple.
+fn named_entry(name_description: &str, value: &Value) -> Value {
+   return json!({
+      "type": "array",
+      "items": [
+         { "description": name_description, "type": "string" },
+         value,
+      ],
+      "minItems": 2,
+      "additionalItems": false,
+   });
+}
+
+/// A boolean that is enabled by default.
+fn switch(description: &str) -> Value {
+   return json!({
+      "description": description,
+      "type": "boolean",
+      "default": true,
+   });
+}
+
+fn string_array() -> Value {
+   return json!({
+      "type": "array",
+      "items": { "type": "string" },
+   });
+}
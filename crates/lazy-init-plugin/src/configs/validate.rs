@@ -0,0 +1,266 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+
+use super::*;
+use diagnostics::{Diagnostic, DiagnosticCode};
+use introspect::field_names;
+
+/// Parses the plugin config, returning a diagnostic for each unknown option
+/// and invalid value. Each diagnostic contains the path of the value within
+/// the config, e.g. `customFns[0][1].lz`, and suggests the closest option
+/// when one is misspelled.
+///
+/// # Errors
+/// Returns the diagnostics when the config is invalid, which should be
+/// emitted using [`Diagnostic::emit`].
+pub fn parse_config(json: &str) -> Result<PluginConfig, Vec<Diagnostic>> {
+   let value: Value = serde_json::from_str(json).map_err(|err| {
+      return vec![Validator::default().diagnostic(&err.to_string(), None)];
+   })?;
+
+   let mut v = Validator::default();
+   v.plugin_config(&value);
+
+   if !v.diagnostics.is_empty() {
+      return Err(v.diagnostics);
+   }
+   // Any error the validator did not catch is reported without a path.
+   return serde_json::from_value(value).map_err(|err| {
+      return vec![v.diagnostic(&err.to_string(), None)];
+   });
+}
+
+#[derive(Debug)]
+enum Segment {
+   Key(String),
+   Index(usize),
+}
+
+#[derive(Debug, Default)]
+struct Validator {
+   path: Vec<Segment>,
+   diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator {
+   fn plugin_config(&mut self, value: &Value) {
+      self.object::<PluginConfig>(value, |v, key, value| match key {
+         "customFns" => v.named_entries(value, Self::custom_lazy_fns),
+         "diagnostics" => v.diagnostics(value),
+         "hoist" => v.switches::<HoistConfig>(value),
+         "block" => v.switches::<BlockConfig>(value),
+         "operator" => {
+            if !v.field::<PluginConfig>(key, value) {
+               set_help(
+                  &mut v.diagnostics,
+                  "Accepted values are `0` (`??`) and `1` (`||`).",
+               );
+            }
+         }
+         _ => {
+            v.field::<PluginConfig>(key, value);
+         }
+      });
+   }
+
+   fn custom_lazy_fns(&mut self, value: &Value) {
+      self.object::<CustomLazyFns>(value, |v, key, value| {
+         if key == "lz" {
+            return v.named_entries(value, Self::custom_lz_method_map);
+         }
+         v.field::<CustomLazyFns>(key, value);
+      });
+   }
+
+   fn custom_lz_method_map(&mut self, value: &Value) {
+      self.object::<CustomLzMethodMap>(value, |v, key, value| {
+         v.field::<CustomLzMethodMap>(key, value);
+      });
+   }
+
+   fn switches<T: DeserializeOwned>(&mut self, value: &Value) {
+      self.object::<T>(value, |v, key, value| {
+         v.field::<T>(key, value);
+      });
+   }
+
+   fn diagnostics(&mut self, value: &Value) {
+      let map = unwrap_or!(self.value::<Map<String, Value>>(value), return);
+
+      for (key, level) in &map {
+         self.path.push(Segment::Key(key.clone()));
+
+         if DiagnosticCode::find(key).is_some() {
+            let _ = self.value::<DiagnosticLevel>(level);
+         } else {
+            self.unknown(
+               "unknown diagnostic",
+               key,
+               DiagnosticCode::KEYS.iter().copied(),
+            );
+         }
+         self.path.pop();
+      }
+   }
+
+   /// Validates each key of an object. Unknown keys are reported, the value
+   /// of each known key is passed to `f`.
+   fn object<T: DeserializeOwned>(
+      &mut self,
+      value: &Value,
+      mut f: impl FnMut(&mut Self, &str, &Value),
+   ) {
+      let map = unwrap_or!(self.value::<Map<String, Value>>(value), return);
+      let fields = field_names::<T>();
+
+      for (key, value) in &map {
+         self.path.push(Segment::Key(key.clone()));
+
+         if fields.contains(&key.as_str()) {
+            f(self, key, value);
+         } else {
+            self.unknown("unknown option", key, fields.iter().copied());
+         }
+         self.path.pop();
+      }
+   }
+
+   /// Validates an array of `[name, value]` entries, passing each value to
+   /// `f`.
+   fn named_entries(&mut self, value: &Value, f: fn(&mut Self, &Value)) {
+      let entries = unwrap_or!(self.value::<Vec<Value>>(value), return);
+
+      for (i, entry) in entries.iter().enumerate() {
+         self.path.push(Segment::Index(i));
+
+         if let Some((_, value)) = self.value::<(JsWord, Value)>(entry) {
+            self.path.push(Segment::Index(1));
+            f(self, &value);
+            self.path.pop();
+         }
+         self.path.pop();
+      }
+   }
+
+   /// Validates the value of a single field by parsing `T` with only that
+   /// field, since every field has a default. Returns whether it is valid.
+   fn field<T: DeserializeOwned>(&mut self, key: &str, value: &Value) -> bool {
+      let mut map = Map::new();
+      map.insert(key.to_owned(), value.clone());
+
+      return self.value::<T>(&Value::Object(map)).is_some();
+   }
+
+   fn value<T: DeserializeOwned>(&mut self, value: &Value) -> Option<T> {
+      match serde_json::from_value(value.clone()) {
+         Ok(v) => return Some(v),
+         Err(err) => {
+            let diagnostic = self.diagnostic(&err.to_string(), None);
+            self.diagnostics.push(diagnostic);
+            return None;
+         }
+      }
+   }
+
+   fn unknown<'a>(
+      &mut self,
+      msg: &str,
+      key: &str,
+      expected: impl Iterator<Item = &'a str> + Clone,
+   ) {
+      let help = if let Some(closest) = closest(key, expected.clone()) {
+         format!("Did you mean `{closest}`?")
+      } else {
+         let expected: Vec<_> =
+            expected.map(|e| return format!("`{e}`")).collect();
+         format!("Expected one of {}.", expected.join(", "))
+      };
+      let diagnostic = self.diagnostic(&format!("{msg} `{key}`"), Some(help));
+      self.diagnostics.push(diagnostic);
+   }
+
+   fn diagnostic(&self, msg: &str, help: Option<String>) -> Diagnostic {
+      let message = if self.path.is_empty() {
+         format!("Invalid plugin config: {msg}.")
+      } else {
+         format!(
+            "Invalid plugin config at `{}`: {msg}.",
+            self.path()
+         )
+      };
+      let diagnostic =
+         Diagnostic::new(DiagnosticCode::InvalidConfig, DUMMY_SP, message);
+
+      if let Some(help) = help {
+         return diagnostic.with_help(help);
+      }
+      return diagnostic;
+   }
+
+   /// E.g. `customFns[0][1].lz`.
+   fn path(&self) -> String {
+      let mut path = String::new();
+
+      for segment in &self.path {
+         match segment {
+            Segment::Key(key) => {
+               if !path.is_empty() {
+                  path.push('.');
+               }
+               path.push_str(key);
+            }
+            Segment::Index(i) => path.push_str(&format!("[{i}]")),
+         }
+      }
+      return path;
+   }
+}
+
+/// Adds help to the last diagnostic that was reported.
+fn set_help(diagnostics: &mut [Diagnostic], help: &str) {
+   if let Some(diagnostic) = diagnostics.last_mut() {
+      diagnostic.help = Some(help.to_owned());
+   }
+}
+
+/// Returns the known key closest to a misspelled key, if any is close enough.
+/// A key that is the start of a known key, e.g. `unreachable` for
+/// `unreachable-code`, is also considered close.
+fn closest<'a>(
+   key: &str,
+   expected: impl Iterator<Item = &'a str>,
+) -> Option<&'a str> {
+   let key = key.to_lowercase();
+   let max_distance = (key.chars().count() / 3).max(1);
+
+   return expected
+      .filter_map(|e| {
+         let lower = e.to_lowercase();
+         let distance = edit_distance(&key, &lower);
+         let is_prefix = key.len() >= 3 && lower.starts_with(&key);
+
+         return (distance <= max_distance || is_prefix)
+            .then_some((distance, e));
+      })
+      .min_by_key(|(d, _)| return *d)
+      .map(|(_, e)| return e);
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+   let b: Vec<char> = b.chars().collect();
+   let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+   for (i, ca) in a.chars().enumerate() {
+      let mut curr = vec![i + 1];
+
+      for (j, cb) in b.iter().enumerate() {
+         let cost = usize::from(ca != *cb);
+         curr.push((prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1));
+      }
+      prev = curr;
+   }
+   return prev[b.len()];
+}
@@ -0,0 +1,246 @@
+use serde::{de, Deserialize, Deserializer};
+use swc_common::{
+   errors::{DiagnosticId, HANDLER},
+   Span,
+};
+
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+   Error,
+   Warning,
+}
+
+macro_rules! diagnostic_codes {
+   ($(
+      $(#[doc = $doc:literal])+
+      $variant:ident = ($code:literal, $name:literal, $severity:ident)
+   ),+ $(,)?) => {
+      /// A stable identifier of a diagnostic. The code (e.g. `LZ0001`) and
+      /// name (e.g. `block-missing-return`) never change once released, so
+      /// they can be used to filter diagnostics or link to their docs.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub enum DiagnosticCode {
+         $(
+            $(#[doc = $doc])+
+            $variant,
+         )+
+      }
+
+      impl DiagnosticCode {
+         pub const ALL: &'static [DiagnosticCode] = &[
+            $(DiagnosticCode::$variant),+
+         ];
+
+         /// The codes and names that a diagnostic can be referred to by.
+         pub const KEYS: &'static [&'static str] = &[$($code, $name),+];
+
+         /// E.g. `LZ0001`.
+         pub fn code(self) -> &'static str {
+            return match self {
+               $(DiagnosticCode::$variant => $code,)+
+            };
+         }
+
+         /// E.g. `block-missing-return`.
+         pub fn name(self) -> &'static str {
+            return match self {
+               $(DiagnosticCode::$variant => $name,)+
+            };
+         }
+
+         pub fn default_severity(self) -> Severity {
+            return match self {
+               $(DiagnosticCode::$variant => Severity::$severity,)+
+            };
+         }
+      }
+   };
+}
+
+diagnostic_codes!(
+   /// The block does not contain a `return` statement.
+   BlockMissingReturn = ("LZ0001", "block-missing-return", Error),
+   /// The final statement in the block does not always return.
+   BlockFinalStmtNoReturn = ("LZ0002", "block-final-stmt-no-return", Error),
+   /// The block was called without an argument.
+   BlockMissingArgument = ("LZ0003", "block-missing-argument", Error),
+   /// The argument passed to the block is not a function.
+   BlockInvalidArgument = ("LZ0004", "block-invalid-argument", Error),
+   /// The function passed to the block has no statements.
+   BlockEmpty = ("LZ0005", "block-empty", Error),
+   /// An async function was passed to a block outside of an async function.
+   BlockAsyncOutsideAsyncFn = ("LZ0006", "block-async-outside-async-fn", Error),
+   /// A generator function was passed to a block outside of a generator
+   /// function.
+   BlockGeneratorOutsideGeneratorFn = (
+      "LZ0007",
+      "block-generator-outside-generator-fn",
+      Error
+   ),
+   /// The function expression passed to the block uses `arguments` or
+   /// `super`, which cannot be inlined. `this` and `new.target` are replaced
+   /// with `undefined`, their values in strict code, since only modules are
+   /// transformed.
+   BlockFnExprUnsupported = ("LZ0008", "block-fn-expr-unsupported", Error),
+   /// The block does not initialize a variable declaration.
+   BlockInvalidPosition = ("LZ0009", "block-invalid-position", Error),
+   /// The block initializes a declaration with multiple declarators.
+   BlockMultipleDeclarators = ("LZ0010", "block-multiple-declarators", Error),
+   /// The block initializes a `let` declaration with a destructuring
+   /// pattern.
+   BlockDestructuring = ("LZ0011", "block-destructuring", Error),
+   /// A statement in the block can never execute.
+   UnreachableCode = ("LZ0012", "unreachable-code", Warning),
+   /// The value passed to a hoisted call references a local binding, which
+   /// is only read the first time the call is evaluated.
+   HoistCapturesLocal = ("LZ0013", "hoist-captures-local", Warning),
+   /// A primitive value is passed to `lz()`, which has no benefit.
+   HoistPrimitive = ("LZ0014", "hoist-primitive", Warning),
+   /// The block cannot be inlined and is wrapped in a labeled statement.
+   /// Only reported when the `reportWrapped` option is enabled.
+   BlockWrapped = ("LZ0015", "block-wrapped", Warning),
+   /// The block was forced to be wrapped using the deprecated second
+   /// argument instead of the `/* lazy-init-force-wrapped */` comment.
+   BlockForceWrappedArgument =
+      ("LZ0016", "block-force-wrapped-argument", Warning),
+   /// The plugin config is not valid JSON or contains an unknown option or
+   /// an invalid value.
+   InvalidConfig = ("LZ0017", "invalid-config", Error),
+   /// An async function was passed to a block whose value is not awaited.
+   BlockAsyncNotAwaited = ("LZ0018", "block-async-not-awaited", Error),
+   /// A generator function was passed to a block whose value is not
+   /// delegated to using `yield*`.
+   BlockGeneratorNotDelegated =
+      ("LZ0019", "block-generator-not-delegated", Error),
+);
+
+impl DiagnosticCode {
+   /// Finds the diagnostic referred to by its code or name.
+   pub fn find(key: &str) -> Option<Self> {
+      return DiagnosticCode::ALL
+         .iter()
+         .find(|c| return c.code() == key || c.name() == key)
+         .copied();
+   }
+}
+
+impl<'de> Deserialize<'de> for DiagnosticCode {
+   fn deserialize<D: Deserializer<'de>>(
+      deserializer: D,
+   ) -> Result<Self, D::Error> {
+      let key = String::deserialize(deserializer)?;
+
+      return DiagnosticCode::find(&key).ok_or_else(|| {
+         return de::Error::unknown_variant(&key, DiagnosticCode::KEYS);
+      });
+   }
+}
+
+/// A diagnostic that is reported through the SWC handler as an error or a
+/// warning, see [`Diagnostic::emit`].
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+   pub code: DiagnosticCode,
+   pub severity: Severity,
+   pub span: Span,
+   pub message: String,
+   pub help: Option<String>,
+   pub notes: Vec<String>,
+   /// Secondary spans that are labeled with a message.
+   pub labels: Vec<(Span, String)>,
+}
+
+impl Diagnostic {
+   /// Creates a diagnostic with the default severity of the code.
+   pub fn new(code: DiagnosticCode, span: Span, message: String) -> Self {
+      return Self {
+         code,
+         severity: code.default_severity(),
+         span,
+         message,
+         help: None,
+         notes: Vec::new(),
+         labels: Vec::new(),
+      };
+   }
+
+   #[must_use]
+   pub fn with_severity(mut self, severity: Severity) -> Self {
+      self.severity = severity;
+      return self;
+   }
+
+   #[must_use]
+   pub fn with_help(mut self, help: String) -> Self {
+      self.help = Some(help);
+      return self;
+   }
+
+   #[must_use]
+   pub fn with_note(mut self, note: String) -> Self {
+      self.notes.push(note);
+      return self;
+   }
+
+   #[must_use]
+   pub fn with_label(mut self, span: Span, label: String) -> Self {
+      self.labels.push((span, label));
+      return self;
+   }
+
+   pub fn emit(&self) {
+      HANDLER.with(|handler| {
+         let msg = error_msg!("{}", self.message);
+         let code = self.code.code().to_owned();
+
+         let mut builder = match self.severity {
+            Severity::Error => handler.struct_span_err_with_code(
+               self.span,
+               &msg,
+               DiagnosticId::Error(code),
+            ),
+            Severity::Warning => handler.struct_span_warn_with_code(
+               self.span,
+               &msg,
+               DiagnosticId::Lint(code),
+            ),
+         };
+
+         for (span, label) in &self.labels {
+            builder.span_label(*span, label);
+         }
+         if let Some(help) = &self.help {
+            builder.help(help);
+         }
+         for note in &self.notes {
+            builder.note(note);
+         }
+         return builder.emit();
+      });
+   }
+}
+
+#[cfg(test)]
+mod diagnostic_code_test {
+   use super::*;
+
+   #[test]
+   fn unique() {
+      for (idx, a) in DiagnosticCode::ALL.iter().enumerate() {
+         assert!(
+            a.code().len() == 6 &&
+               a.code().starts_with("LZ") &&
+               a.code()[2..].chars().all(|c| return c.is_ascii_digit()),
+            "Invalid code: {}",
+            a.code()
+         );
+
+         for b in &DiagnosticCode::ALL[(idx + 1)..] {
+            assert_ne!(a.code(), b.code());
+            assert_ne!(a.name(), b.name());
+         }
+      }
+   }
+}
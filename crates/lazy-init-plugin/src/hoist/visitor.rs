@@ -1,14 +1,19 @@
 // use either::Either;
 use std::vec;
-use swc_common::{util::take::Take, DUMMY_SP};
+use swc_common::{
+   collections::AHashSet, util::take::Take, Mark, Span, Spanned, SyntaxContext,
+   DUMMY_SP,
+};
 use swc_ecma_ast::*;
-use swc_ecma_utils::{undefined, ExprFactory};
+use swc_ecma_utils::{collect_decls, undefined, ExprFactory};
 use swc_ecma_visit::{
-   noop_visit_mut_type, visit_mut_obj_and_computed, VisitMut, VisitMutWith,
+   noop_visit_mut_type, noop_visit_type, visit_mut_obj_and_computed,
+   visit_obj_and_computed, Visit, VisitMut, VisitMutWith, VisitWith,
 };
 
 use super::*;
 use crate::visitor::{LazyVisitor, LzMethodFound};
+use diagnostics::{Diagnostic, DiagnosticCode};
 use lazy_init_pkg::{ExportedFn, LzMethodKind};
 use utils::LazyVar;
 
@@ -45,29 +50,17 @@ impl LazyHoistVisitor<'_> {
    ) {
       debug_assert!(!matches!(method.kind, LzMethodKind::Block));
 
+      // The generated code has the span of the call it replaces.
+      let span = call_expr.span;
       let mut lazy_args = call_expr.args.take();
       let is_async = matches!(method.kind, LzMethodKind::Async);
 
       if is_async {
-         let no_options_arg = lazy_args.get(1).is_none();
-
-         if no_options_arg {
-            lazy_args.insert(1, undefined(DUMMY_SP).as_arg());
-         }
-         // Pass unique key as the third argument.
-         lazy_args.insert(
-            2,
-            Str {
-               span: DUMMY_SP,
-               value: utils::rand_string().into(),
-               raw: None,
-            }
-            .as_arg(),
-         );
+         Self::insert_async_key(span, &mut lazy_args);
       }
 
       let mut initializer = Box::new(Expr::Call(CallExpr {
-         span: DUMMY_SP,
+         span,
          callee: call_expr.callee.take(),
          args: lazy_args,
          type_args: None,
@@ -76,32 +69,39 @@ impl LazyHoistVisitor<'_> {
       if is_async {
          // `await initializer()`
          initializer = Box::new(Expr::Await(AwaitExpr {
-            span: DUMMY_SP,
+            span,
             arg: initializer,
          }));
       }
 
       let lazy_var = LazyVar::new();
-      self.main.lazy_vars_inserted.push(lazy_var.declarator);
 
       // `lazyVar = initializer()`
-      let assign_to_lazy_var = Box::new(
-         initializer
-            .make_assign_to(op!("="), lazy_var.ident.clone().as_pat_or_expr()),
-      );
+      let assign_to_lazy_var = Box::new(Expr::Assign(AssignExpr {
+         span,
+         op: op!("="),
+         left: lazy_var.ident_at(span).as_pat_or_expr(),
+         right: initializer,
+      }));
       // `lazyVar ?? (lazyVar = initializer())`
-      let mut get_or_initialize = Expr::Ident(lazy_var.ident).make_bin(
-         self.main.config.operator.to_bin_op(),
-         Expr::Paren(ParenExpr {
-            span: DUMMY_SP,
+      let mut get_or_initialize = Expr::Bin(BinExpr {
+         span,
+         op: self.main.config.operator.to_bin_op(),
+         left: Box::new(Expr::Ident(lazy_var.ident_at(span))),
+         right: Box::new(Expr::Paren(ParenExpr {
+            span,
             expr: assign_to_lazy_var,
-         }),
-      );
+         })),
+      });
 
       if is_async {
          // `(lazyVar ?? (lazyVar = await initializer()))`
-         get_or_initialize = get_or_initialize.wrap_with_paren();
+         get_or_initialize = Expr::Paren(ParenExpr {
+            span,
+            expr: Box::new(get_or_initialize),
+         });
       }
+      self.main.lazy_vars_inserted.push(lazy_var.declarator);
 
       call_expr.args = vec![
          ExprOrSpread {
@@ -116,6 +116,132 @@ impl LazyHoistVisitor<'_> {
       // the parent node and replacing the actual call expression.
       call_expr.callee = utils::replace_identity_fn();
    }
+
+   /// Passes a unique key as the third argument of `lz.async()`, which the
+   /// runtime requires even when the call is not hoisted.
+   fn insert_async_key(span: Span, args: &mut Vec<ExprOrSpread>) {
+      let no_options_arg = args.get(1).is_none();
+
+      if no_options_arg {
+         args.insert(1, undefined(span).as_arg());
+      }
+      args.insert(
+         2,
+         Str {
+            span: DUMMY_SP,
+            value: utils::rand_string().into(),
+            raw: None,
+         }
+         .as_arg(),
+      );
+   }
+}
+
+impl LazyHoistVisitor<'_> {
+   /// Reports values that are likely a mistake to hoist.
+   fn check_value(&self, call_expr: &CallExpr, method: &LzMethodFound) {
+      let value = unwrap_or!(call_expr.args.first(), return);
+
+      if method.kind == LzMethodKind::Obj && is_primitive(&value.expr) {
+         return self.main.report(
+            Diagnostic::new(
+               DiagnosticCode::HoistPrimitive,
+               value.expr.span(),
+               format!(
+                  "Passing a primitive value to {} has no benefit, it is only \
+                   useful for non-primitive values.",
+                  method.format()
+               ),
+            )
+            .with_help("Use the value directly.".to_owned()),
+         );
+      }
+
+      // All the lazy functions are imported, so they are declared at the top
+      // level of the module.
+      let top_level_ctxt =
+         unwrap_or!(self.main.imported_fns.first(), return).id.1;
+      let mut v = LocalFinder {
+         top_level_ctxt,
+         unresolved_mark: self.main.metadata.unresolved_mark,
+         declared: collect_decls(&*value.expr),
+         found: None,
+      };
+      value.expr.visit_with(&mut v);
+
+      if let Some(local) = v.found {
+         self.main.report(
+            Diagnostic::new(
+               DiagnosticCode::HoistCapturesLocal,
+               local.span,
+               format!(
+                  "The value passed to {} references the local binding `{}`, \
+                   which is only read the first time the value is created.",
+                  method.format(),
+                  local.sym
+               ),
+            )
+            .with_label(
+               call_expr.callee.span(),
+               "the value is hoisted here".to_owned(),
+            )
+            .with_help(
+               "Pass the binding as an argument of a function instead, or \
+                move the binding to the top level of the module."
+                  .to_owned(),
+            ),
+         );
+      }
+   }
+}
+
+fn is_primitive(expr: &Expr) -> bool {
+   return match expr {
+      Expr::Lit(lit) => !matches!(lit, Lit::Regex(_) | Lit::JSXText(_)),
+      Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+      Expr::Unary(UnaryExpr {
+         op: op!(unary, "-") | op!(unary, "+") | op!("!") | op!("void"),
+         arg,
+         ..
+      }) => is_primitive(arg),
+      _ => false,
+   };
+}
+
+/// Finds the first identifier that references a local binding. A binding is
+/// local when it is not declared at the top level of the module, not a
+/// global and not declared within the visited node itself.
+struct LocalFinder {
+   top_level_ctxt: SyntaxContext,
+   unresolved_mark: Mark,
+   declared: AHashSet<Id>,
+   found: Option<Ident>,
+}
+
+impl Visit for LocalFinder {
+   noop_visit_type!();
+
+   visit_obj_and_computed!();
+
+   fn visit_ident(&mut self, ident: &Ident) {
+      bail_if!(self.found.is_some());
+
+      let ctxt = ident.span.ctxt;
+      let is_local = ctxt != self.top_level_ctxt &&
+         ctxt.outer() != self.unresolved_mark &&
+         !self.declared.contains(&ident.to_id());
+
+      if is_local {
+         self.found = Some(ident.clone());
+      }
+   }
+
+   fn visit_prop_name(&mut self, prop_name: &PropName) {
+      // Only a computed key can reference a binding.
+      if let PropName::Computed(computed) = prop_name {
+         computed.visit_with(self);
+      }
+   }
 }
 
 impl VisitMut for LazyHoistVisitor<'_> {
@@ -124,14 +250,30 @@ impl VisitMut for LazyHoistVisitor<'_> {
    visit_mut_obj_and_computed!();
 
    fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+      let method = self.main.find_lazy_method(call_expr);
+      let is_enabled = method.as_ref().map_or(false, |m| {
+         return self.main.config.is_enabled(m.kind);
+      });
+
+      // The value is checked before any nested calls are transformed.
+      if let Some(method) = &method {
+         if is_enabled && method.kind != LzMethodKind::Block {
+            self.check_value(call_expr, method);
+         }
+      }
       call_expr.visit_mut_children_with(self);
 
-      if let Some(method) = self.main.find_lazy_method(call_expr) {
-         if method.kind == LzMethodKind::Block {
-            self.found_block = true;
-         } else {
-            self.handle_hoist(call_expr, &method);
+      let method = unwrap_or!(method, return);
+
+      if !is_enabled {
+         // Disabled kinds are left as runtime calls.
+         if method.kind == LzMethodKind::Async {
+            Self::insert_async_key(call_expr.span, &mut call_expr.args);
          }
+      } else if method.kind == LzMethodKind::Block {
+         self.found_block = true;
+      } else {
+         self.handle_hoist(call_expr, &method);
       }
    }
 }
@@ -30,10 +30,12 @@
 pub mod block;
 pub mod configs;
 pub mod context;
+pub mod diagnostics;
 pub mod hoist;
 pub mod lazy_init_pkg;
 pub mod macros;
 pub mod normalizer;
+pub mod pragmas;
 pub mod utils;
 pub mod visitor;
 
@@ -42,7 +44,8 @@ pub mod tests;
 
 #[cfg(feature = "use-plugin_transform")]
 use swc_core::plugin::{
-   plugin_transform, proxies::TransformPluginProgramMetadata,
+   metadata::TransformPluginMetadataContextKind, plugin_transform,
+   proxies::TransformPluginProgramMetadata,
 };
 #[cfg(feature = "use-plugin_transform")]
 use swc_ecma_ast::Program;
@@ -62,20 +65,40 @@ fn lazy_init_plugin(
 ) -> Program {
    tracing::debug!("Running => lazy_init_plugin ...");
 
-   let config: configs::PluginConfig = serde_json::from_str(
+   let config = match configs::parse_config(
       &data
          .get_transform_plugin_config()
          .expect("failed to get plugin config for lazy-init-plugin"),
-   )
-   .expect("failed to parse config for lazy-init-plugin");
+   ) {
+      Ok(config) => config,
+      Err(diagnostics) => {
+         for diagnostic in &diagnostics {
+            diagnostic.emit();
+         }
+         return program;
+      }
+   };
 
    if config.disable {
       return program;
    }
 
+   let filename =
+      data.get_context(&TransformPluginMetadataContextKind::Filename);
+   if let Some(filename) = filename {
+      let cwd = data.get_context(&TransformPluginMetadataContextKind::Cwd);
+
+      if config.is_excluded(&filename, cwd.as_deref()) {
+         return program;
+      }
+   }
+
    return program.fold_with(&mut as_folder(visitor::LazyVisitor::new(
       config,
       visitor::ProgramMetadata {
+         comments: data.comments.map(|c| {
+            return Box::new(c) as Box<dyn swc_common::comments::Comments>;
+         }),
          unresolved_mark: data.unresolved_mark,
       },
    )));
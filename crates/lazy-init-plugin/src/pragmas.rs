@@ -0,0 +1,91 @@
+use swc_common::{
+   comments::{Comment, Comments},
+   BytePos,
+};
+use swc_ecma_ast::*;
+
+use super::*;
+
+/// A comment that changes how the plugin handles a file or a call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pragma {
+   /// `/* lazy-init-disable */` - Placed at the top of a file, the file is
+   /// not transformed.
+   Disable,
+   /// `/* lazy-init-ignore */` - Placed before a call, the call is not
+   /// transformed and is left as a runtime call.
+   Ignore,
+   /// `/* lazy-init-force-wrapped */` - Placed before a `lz.block()` call,
+   /// the block is always wrapped in a labeled statement.
+   ForceWrapped,
+}
+
+/// The directive that disables the plugin for a file, the same as the
+/// [`Pragma::Disable`] comment.
+pub const DISABLE_DIRECTIVE: &str = "use no lazy";
+
+impl Pragma {
+   pub fn text(self) -> &'static str {
+      return match self {
+         Pragma::Disable => "lazy-init-disable",
+         Pragma::Ignore => "lazy-init-ignore",
+         Pragma::ForceWrapped => "lazy-init-force-wrapped",
+      };
+   }
+
+   /// Whether the comment is this pragma. Anything after the pragma, e.g. a
+   /// reason, is ignored.
+   /// ```ts
+   /// /* lazy-init-ignore */
+   /// /** lazy-init-ignore */
+   /// // lazy-init-ignore: the value must be created on each call.
+   /// ```
+   fn matches(self, comment: &Comment) -> bool {
+      let text = comment.text.trim_start_matches('*').trim_start();
+
+      return text.strip_prefix(self.text()).map_or(false, |rest| {
+         return !rest.starts_with(|c: char| {
+            return c.is_alphanumeric() || c == '-' || c == '_';
+         });
+      });
+   }
+}
+
+/// Whether the pragma is one of the leading comments at `pos`.
+pub fn has_pragma(
+   comments: Option<&dyn Comments>,
+   pos: BytePos,
+   pragma: Pragma,
+) -> bool {
+   let comments = unwrap_or!(comments, return false);
+
+   return comments.get_leading(pos).map_or(false, |comments| {
+      return comments.iter().any(|c| return pragma.matches(c));
+   });
+}
+
+/// Whether the file is opted out of the transform, using either the
+/// `/* lazy-init-disable */` comment at the top of the file or the
+/// `"use no lazy"` directive.
+pub fn is_disabled(comments: Option<&dyn Comments>, module: &Module) -> bool {
+   if has_pragma(comments, module.span.lo, Pragma::Disable) {
+      return true;
+   }
+
+   // Directives must precede any other statement.
+   for item in &module.body {
+      let lit = unwrap_or!(
+         item
+            .as_stmt()
+            .and_then(Stmt::as_expr)
+            .and_then(|s| return s.expr.as_lit()),
+         break
+      );
+      match lit {
+         Lit::Str(s) if &*s.value == DISABLE_DIRECTIVE => return true,
+         Lit::Str(_) => {}
+         _ => break,
+      }
+   }
+   return false;
+}
@@ -114,6 +114,42 @@ function h0() {
    }
 }
 
+function h1() {
+   switch (val) {
+      default: return 2;
+      case A: return 0;
+      case B: return 1;
+   }
+}
+
+function h2() {
+   switch (val) {
+      case A: return 0;
+      default:
+      case B: return 1;
+      case C: return 2;
+   }
+}
+
+function h3() {
+   switch (val) {
+      default:
+         log(val);
+      case A: {
+         val += A;
+      }
+      case B: return 1;
+   }
+}
+
+function h4() {
+   switch (val) {
+      case A: return 0;
+      default: throw new Error("unknown");
+      case B: return 1;
+   }
+}
+
 function i0() {
    switch (val) {
       case A: { 
@@ -147,62 +183,44 @@ function j1() {
 }
 
 function j2() {
-   try {
-      throw new Error("try");
-   } finally {
-      return saved();
-   }
-}
-
-function j3() {
-   try {
-      return passed();
-   } finally {
-      return saved();
-   }
-}
-
-function j4() {
    try {
       return passed();
    } catch (error) {
       return failed();
    } finally {
-      return saved();
+      //
    }
 }
 
-function j5() {
+function j3() {
    try {
       return passed();
    } catch (error) {
       throw new Error("catch");
    } finally {
-      return saved();
+      //
    }
 }
 
-function j6() {
+
+function j4() {
    try {
       return passed();
-   } catch (error) {
-      return failed();
    } finally {
-      //
+      cleanup();
    }
 }
 
-function j7() {
+function j5() {
    try {
       return passed();
    } catch (error) {
-      throw new Error("catch");
+      return failed();
    } finally {
-      //
+      throw new Error("finally");
    }
 }
 
-
 function k0() {
    if (cond) {
       try {
@@ -215,6 +233,79 @@ function k0() {
    }
 }
 
+async function l0() {
+   const res = await fetch(url);
+   if (!res.ok) {
+      return null;
+   }
+   return await res.json();
+}
+async function l1() {
+   try {
+      return await passed();
+   } catch (error) {
+      return await failed();
+   }
+}
+
+function m0() {
+   if (a) return 1;
+   throw new Error("unreachable");
+}
+function m1() {
+   if (a) {
+      return 1;
+   }
+   if (b) {
+      return 2;
+   }
+   throw new Error("unreachable");
+}
+function m2() {
+   if (!a) throw new Error("guard");
+   if (!b) {
+      throw new Error("guard");
+   }
+   return 1;
+}
+function m3() {
+   switch (val) {
+      case A: return 0;
+      case B: throw new Error("B");
+      default: throw new Error("unknown");
+   }
+}
+function m4() {
+   if (a) {
+      throw new Error("a");
+   } else if (b) {
+      return 1;
+   } else {
+      throw new Error("c");
+   }
+}
+function m5() {
+   if (a) return 1;
+   if (b) throw new Error("b");
+   throw new Error("c");
+}
+function m6() {
+   try {
+      if (a) throw new Error("a");
+      throw new Error("b");
+   } catch (error) {
+      return 1;
+   }
+}
+function m7() {
+   if (a) {
+      value = 1;
+   } else {
+      throw new Error("a");
+   }
+   return value;
+}
+
 function z0() {
    for (let i = 0; i < 5; i++) {
       if (condA) break;
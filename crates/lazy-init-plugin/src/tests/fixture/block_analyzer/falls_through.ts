@@ -40,6 +40,31 @@ function h0() {
    return 0;
 }
 
+function h1() {
+   switch (val) {
+      default: return 0;
+      case A: return 1;
+      case B:
+   }
+}
+
+function h2() {
+   switch (val) {
+      case A: return 0;
+      default: log(val);
+   }
+   return 1;
+}
+
+function h3() {
+   switch (val) {
+      default:
+         if (cond) break;
+         return 0;
+      case A: return 1;
+   }
+}
+
 function j0() {
    try {
       //
@@ -87,6 +112,57 @@ function j4() {
    }
 }
 
+function j5() {
+   try {
+      throw new Error("try");
+   } finally {
+      return saved();
+   }
+}
+
+function j6() {
+   try {
+      return passed();
+   } finally {
+      return saved();
+   }
+}
+
+function j7() {
+   try {
+      return passed();
+   } catch (error) {
+      return failed();
+   } finally {
+      return saved();
+   }
+}
+
+function j8() {
+   try {
+      return passed();
+   } catch (error) {
+      throw new Error("catch");
+   } finally {
+      return saved();
+   }
+}
+
+function j9() {
+   try {
+      return passed();
+   } finally {
+      if (cond) return saved();
+   }
+}
+
+function j10() {
+   try {
+      if (cond) return passed();
+   } finally {
+      cleanup();
+   }
+}
 
 function k0() {
    if (cond) {
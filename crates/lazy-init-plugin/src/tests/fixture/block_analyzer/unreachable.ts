@@ -0,0 +1,67 @@
+// @ts-nocheck
+
+function a0() {
+   return 0;
+   log();
+}
+function a1() {
+   if (cond) return 0;
+   throw new Error("oops");
+   log();
+}
+
+function b0() {
+   if (cond) {
+      return 0;
+   } else {
+      return 1;
+   }
+   return 2;
+}
+function b1() {
+   if (cond) return 0;
+   else throw new Error("oops");
+   log();
+}
+
+function c0() {
+   if (cond) {
+      return 0;
+      log();
+   }
+   return 1;
+}
+function c1() {
+   {
+      return 0;
+   }
+   log();
+}
+
+function d0() {
+   try {
+      return 0;
+   } catch {
+      return 1;
+   }
+   return 2;
+}
+function d1() {
+   if (cond) return 0;
+   try {
+      log();
+   } finally {
+      throw new Error("oops");
+   }
+   return 1;
+}
+
+function e0() {
+   switch (key) {
+      case 0:
+         return 0;
+         log();
+      default:
+         return 1;
+   }
+}
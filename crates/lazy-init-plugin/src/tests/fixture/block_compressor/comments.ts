@@ -0,0 +1,61 @@
+// input
+function f0() {
+    // a
+    if (a) {
+        // b
+        lzVar = 0; // c
+    } else {
+        /* d */
+        lzVar = 1;
+    }
+}
+
+function f1() {
+    switch (key) {
+        // a
+        case "a":
+            lzVar = 0;
+            break;
+        case "b":
+            // b
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2; // c
+    }
+}
+
+function f2() {
+    if (a) {
+        // Moved.
+        lzVar = () => {
+            // Kept in the function.
+            return 0;
+        };
+    } else {
+        lzVar = 1;
+    }
+}
+
+// output
+function f0() {
+    // a
+    // b
+    // c
+    /* d */ lzVar = a ? 0 : 1;
+}
+
+function f1() {
+    // a
+    // b
+    // c
+    lzVar = key === "a" ? 0 : key === "b" ? 1 : 2;
+}
+
+function f2() {
+    // Moved.
+    lzVar = a ? ()=>{
+        // Kept in the function.
+        return 0;
+    } : 1;
+}
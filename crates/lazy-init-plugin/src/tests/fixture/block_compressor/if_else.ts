@@ -0,0 +1,108 @@
+// input
+function f0() {
+    if (a) {
+        lzVar = 0;
+    } else {
+        lzVar = 1;
+    }
+}
+
+function f1() {
+    if (a) lzVar = 0;
+    else if (b) lzVar = 1;
+    else if (c) {
+        lzVar = 2;
+    } else lzVar = 3;
+}
+
+function f2() {
+    log();
+    if (a) {
+        if (b) lzVar = 0;
+        else lzVar = 1;
+    } else {
+        lzVar = x, y;
+    }
+}
+
+// Not compressed
+function f3() {
+    if (a) lzVar = 0;
+}
+
+function f4() {
+    if (a) {
+        lzVar = 0;
+    } else if (b) {
+        lzVar = 1;
+    }
+}
+
+function f5() {
+    if (a) {
+        log();
+        lzVar = 0;
+    } else {
+        lzVar = 1;
+    }
+}
+
+function f6() {
+    if (a) other = 0;
+    else lzVar = 1;
+}
+
+function f7() {
+    if (a) lzVar += 1;
+    else lzVar = 1;
+}
+
+// output
+function f0() {
+    lzVar = a ? 0 : 1;
+}
+
+function f1() {
+    lzVar = a ? 0 : b ? 1 : c ? 2 : 3;
+}
+
+function f2() {
+    log();
+    if (a) {
+        lzVar = b ? 0 : 1;
+    } else {
+        lzVar = x, y;
+    }
+}
+
+// Not compressed
+function f3() {
+    if (a) lzVar = 0;
+}
+
+function f4() {
+    if (a) {
+        lzVar = 0;
+    } else if (b) {
+        lzVar = 1;
+    }
+}
+
+function f5() {
+    if (a) {
+        log();
+        lzVar = 0;
+    } else {
+        lzVar = 1;
+    }
+}
+
+function f6() {
+    if (a) other = 0;
+    else lzVar = 1;
+}
+
+function f7() {
+    if (a) lzVar += 1;
+    else lzVar = 1;
+}
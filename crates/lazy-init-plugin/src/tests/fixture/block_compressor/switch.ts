@@ -0,0 +1,244 @@
+// input
+function f0() {
+    switch (key) {
+        case "a":
+            lzVar = 0;
+            break;
+        case "b":
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+function f1() {
+    switch (key) {
+        default:
+        case 0: {
+            lzVar = "zero";
+            break;
+        }
+        case 1:
+        case 2:
+            lzVar = "one or two";
+            break;
+        case 0:
+            lzVar = "unreachable";
+            break;
+        case 3n:
+            lzVar = true;
+    }
+}
+
+// Keys with the same value are grouped, even when not adjacent.
+function f2() {
+    switch (key) {
+        case null:
+            lzVar = 0;
+            break;
+        case false:
+            lzVar = null;
+            break;
+        case "a":
+            lzVar = 0;
+            break;
+        default:
+            lzVar = 2;
+            break;
+    }
+}
+
+function f3() {
+    switch (key) {
+        case "a":
+            lzVar = 0;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+// Not compressed
+function f4() {
+    switch (key) {
+        case "a":
+            lzVar = 0;
+            break;
+        case "b":
+            lzVar = 1;
+            break;
+    }
+}
+
+// The discriminant would be evaluated for each key.
+function f5() {
+    switch (getKey()) {
+        case "a":
+            lzVar = 0;
+            break;
+        case "b":
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+function f6() {
+    switch (key) {
+        case "a":
+            lzVar = 0;
+            break;
+        case b:
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+function f7() {
+    switch (key) {
+        case "a":
+            lzVar = 0;
+        case "b":
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+function f8() {
+    switch (key) {
+        case "a":
+            lzVar = x;
+            break;
+        case "b":
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+function f9() {
+    switch (key) {
+        case "a":
+            lzVar = 0;
+            break;
+        case "b":
+            lzVar = 1;
+            break;
+        default:
+    }
+}
+
+function f10() {
+    switch (key) {
+        default:
+            lzVar = 2;
+    }
+}
+
+// output
+function f0() {
+    lzVar = key === "a" ? 0 : key === "b" ? 1 : 2;
+}
+
+function f1() {
+    lzVar = key === 0 ? "zero" : key === 1 || key === 2 ? "one or two" : key === 3n ? true : "zero";
+}
+
+// Keys with the same value are grouped, even when not adjacent.
+function f2() {
+    lzVar = key === null || key === "a" ? 0 : key === false ? null : 2;
+}
+
+function f3() {
+    lzVar = key === "a" ? 0 : 2;
+}
+
+// Not compressed
+function f4() {
+    switch(key){
+        case "a":
+            lzVar = 0;
+            break;
+        case "b":
+            lzVar = 1;
+            break;
+    }
+}
+
+// The discriminant would be evaluated for each key.
+function f5() {
+    switch(getKey()){
+        case "a":
+            lzVar = 0;
+            break;
+        case "b":
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+function f6() {
+    switch(key){
+        case "a":
+            lzVar = 0;
+            break;
+        case b:
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+function f7() {
+    switch(key){
+        case "a":
+            lzVar = 0;
+        case "b":
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+function f8() {
+    switch(key){
+        case "a":
+            lzVar = x;
+            break;
+        case "b":
+            lzVar = 1;
+            break;
+        default:
+            lzVar = 2;
+    }
+}
+
+function f9() {
+    switch(key){
+        case "a":
+            lzVar = 0;
+            break;
+        case "b":
+            lzVar = 1;
+            break;
+        default:
+    }
+}
+
+function f10() {
+    switch(key){
+        default:
+            lzVar = 2;
+    }
+}
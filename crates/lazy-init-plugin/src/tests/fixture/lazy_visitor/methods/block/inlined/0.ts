@@ -82,26 +82,14 @@ if (cond) {
 export const t5 = lzVar5;
 
 // output.compressed
-var lzVar;
-lzVar = cond ? 0 : 1;
-export const t0 = lzVar;
+export const t0 = cond ? 0 : 1;
 
-var lzVar1;
-lzVar1 = cond ? 0 : 1;
-export const t1 = lzVar1;
+export const t1 = cond ? 0 : 1;
 
-var lzVar2;
-lzVar2 = cond ? 0 : 1;
-export const t2 = lzVar2;
+export const t2 = cond ? 0 : 1;
 
-var lzVar3;
-lzVar3 = cond ? 0 : 1;
-export const t3 = lzVar3;
+export const t3 = cond ? 0 : 1;
 
-var lzVar4;
-lzVar4 = cond ? 0 : 1;
-export const t4 = lzVar4;
+export const t4 = cond ? 0 : 1;
 
-var lzVar5;
-lzVar5 = cond ? 0 : 1;
-export const t5 = lzVar5;
+export const t5 = cond ? 0 : 1;
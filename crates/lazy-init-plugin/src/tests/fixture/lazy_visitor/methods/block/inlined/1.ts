@@ -40,10 +40,6 @@ if (condA) {
 export const t1 = lzVar1;
 
 // output.compressed
-var lzVar;
-lzVar = condA ? 0 : condB ? 1 : 2;
-export const t0 = lzVar;
+export const t0 = condA ? 0 : condB ? 1 : 2;
 
-var lzVar1;
-lzVar1 = condA ? 0 : condB ? 1 : 2;
-export const t1 = lzVar1;
+export const t1 = condA ? 0 : condB ? 1 : 2;
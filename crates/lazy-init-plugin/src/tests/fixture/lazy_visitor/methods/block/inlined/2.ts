@@ -22,25 +22,21 @@ if (cond) {
 }
 export const t0 = lzVar;
 
-var lzVar1;
 if (cond) {
     throw new Error("t1");
 }
-lzVar1 = 1;
-export const t1 = lzVar1;
+export const t1 = 1;
 
 // output.compressed
 var lzVar;
-if (!cond) {
-    lzVar = 1;
+if (cond) {
+    throw new Error("t0");
 } else {
-    throw Error("t0");
+    lzVar = 1;
 }
 export const t0 = lzVar;
 
-var lzVar1;
 if (cond) {
-    throw Error("t1");
+    throw new Error("t1");
 }
-lzVar1 = 1;
-export const t1 = lzVar1;
+export const t1 = 1;
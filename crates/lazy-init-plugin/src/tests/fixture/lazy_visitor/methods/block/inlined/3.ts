@@ -67,10 +67,6 @@ if (A) {
 export const t1 = lzVar1;
 
 // output.compressed
-var lzVar;
-lzVar = A ? B ? 0 : 1 : C ? 0 : 1;
-export const t0 = lzVar;
+export const t0 = A ? B ? 0 : 1 : C ? 0 : 1;
 
-var lzVar1;
-lzVar1 = A ? B ? 0 : 1 : C ? 0 : 1;
-export const t1 = lzVar1;
+export const t1 = A ? B ? 0 : 1 : C ? 0 : 1;
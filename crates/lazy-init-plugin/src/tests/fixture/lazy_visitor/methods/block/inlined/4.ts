@@ -70,18 +70,18 @@ var lzVar1;
 switch(val){
     case A:
         {
-           lzVar1 = 0;
-           break;
+            lzVar1 = 0;
+            break;
         }
     case B:
         {
-           lzVar1 = 1;
-           break;
+            lzVar1 = 1;
+            break;
         }
     default:
         {
-           lzVar1 = 2;
-           break;
+            lzVar1 = 2;
+            break;
         }
 }
 export const t1 = lzVar1;
@@ -91,35 +91,33 @@ switch(val){
     case A:
     case B:
         {
-           lzVar2 = 1;
-           break;
+            lzVar2 = 1;
+            break;
         }
     default:
         {
-           lzVar2 = 2;
-           break;
+            lzVar2 = 2;
+            break;
         }
 }
 export const t2 = lzVar2;
 
-var lzVar3;
 switch(val){
     case A:
         {
-           val += A;
-           break;
+            val += A;
+            break;
         }
     case B:
         {
-           val += B;
+            val += B;
         }
     default:
         {
-           val += 10;
+            val += 10;
         }
 }
-lzVar3 = 0;
-export const t3 = lzVar3;
+export const t3 = 0;
 
 // output.compressed
 var lzVar;
@@ -139,14 +137,20 @@ export const t0 = lzVar;
 var lzVar1;
 switch(val){
     case A:
-        lzVar1 = 0;
-        break;
+        {
+            lzVar1 = 0;
+            break;
+        }
     case B:
-        lzVar1 = 1;
-        break;
+        {
+            lzVar1 = 1;
+            break;
+        }
     default:
-        lzVar1 = 2;
-        break;
+        {
+            lzVar1 = 2;
+            break;
+        }
 }
 export const t1 = lzVar1;
 
@@ -154,23 +158,31 @@ var lzVar2;
 switch(val){
     case A:
     case B:
-        lzVar2 = 1;
-        break;
+        {
+            lzVar2 = 1;
+            break;
+        }
     default:
-        lzVar2 = 2;
-        break;
+        {
+            lzVar2 = 2;
+            break;
+        }
 }
 export const t2 = lzVar2;
 
-var lzVar3;
 switch(val){
     case A:
-        val += A;
-        break;
+        {
+            val += A;
+            break;
+        }
     case B:
-        val += B;
+        {
+            val += B;
+        }
     default:
-        val += 10;
+        {
+            val += 10;
+        }
 }
-lzVar3 = 0;
-export const t3 = lzVar3;
+export const t3 = 0;
@@ -19,42 +19,6 @@ export const t1 = block(() => {
 });
 
 export const t2 = lz.block(() => {
-    try {
-        throw new Error("try");
-    } finally {
-        return saved();
-    }
-});
-
-export const t3 = block(() => {
-    try {
-        return passed();
-    } finally {
-        return saved();
-    }
-});
-
-export const t4 = lz.block(() => {
-    try {
-        return passed();
-    } catch (error) {
-        return failed();
-    } finally {
-        return saved();
-    }
-});
-
-export const t5 = block(() => {
-    try {
-        return passed();
-    } catch (error) {
-        throw new Error("catch");
-    } finally {
-        return saved();
-    }
-});
-
-export const t6 = lz.block(() => {
     try {
         return passed();
     } catch (error) {
@@ -64,7 +28,7 @@ export const t6 = lz.block(() => {
     }
 });
 
-export const t7 = block(() => {
+export const t3 = block(() => {
     try {
         return passed();
     } catch (error) {
@@ -74,7 +38,7 @@ export const t7 = block(() => {
     }
 });
 
-export const t8 = lz.block(() => {
+export const t4 = lz.block(() => {
     if (cond) {
         try {
             return passed();
@@ -87,7 +51,6 @@ export const t8 = lz.block(() => {
 });
 
 // output
-
 var lzVar;
 try {
     lzVar = passed();
@@ -106,71 +69,35 @@ export const t1 = lzVar1;
 
 var lzVar2;
 try {
-    throw new Error("try");
+    lzVar2 = passed();
+} catch (error) {
+    lzVar2 = failed();
 } finally{
-    lzVar2 = saved();
+    then();
 }
 export const t2 = lzVar2;
 
 var lzVar3;
 try {
     lzVar3 = passed();
-} finally{
-    lzVar3 = saved();
-}
-export const t3 = lzVar3;
-
-var lzVar4;
-try {
-    lzVar4 = passed();
-} catch (error) {
-    lzVar4 = failed();
-} finally{
-    lzVar4 = saved();
-}
-export const t4 = lzVar4;
-
-var lzVar5;
-try {
-    lzVar5 = passed();
-} catch (error) {
-    throw new Error("catch");
-} finally{
-    lzVar5 = saved();
-}
-export const t5 = lzVar5;
-
-var lzVar6;
-try {
-    lzVar6 = passed();
-} catch (error) {
-    lzVar6 = failed();
-} finally{
-    then();
-}
-export const t6 = lzVar6;
-
-var lzVar7;
-try {
-    lzVar7 = passed();
 } catch (error) {
     throw new Error("catch");
 } finally{
     then();
 }
-export const t7 = lzVar7;
+export const t3 = lzVar3;
 
-var lzVar8;
+var lzVar4;
 if (cond) {
     try {
-        lzVar8 = passed();
+        lzVar4 = passed();
     } catch (error) {
-        lzVar8 = failed();
+        lzVar4 = failed();
     }
 } else {
-    lzVar8 = 1;
+    lzVar4 = 1;
 }
-export const t8 = lzVar8;
+export const t4 = lzVar4;
 
 // output.compressed
 var lzVar;
@@ -183,7 +110,7 @@ export const t0 = lzVar;
 
 var lzVar1;
 try {
-    throw Error("try");
+    throw new Error("try");
 } catch (error) {
     lzVar1 = failed();
 }
@@ -191,68 +118,32 @@ export const t1 = lzVar1;
 
 var lzVar2;
 try {
-    throw Error("try");
+    lzVar2 = passed();
+} catch (error) {
+    lzVar2 = failed();
 } finally{
-    lzVar2 = saved();
+    then();
 }
 export const t2 = lzVar2;
 
 var lzVar3;
 try {
     lzVar3 = passed();
-} finally{
-    lzVar3 = saved();
-}
-export const t3 = lzVar3;
-
-var lzVar4;
-try {
-    lzVar4 = passed();
 } catch (error) {
-    lzVar4 = failed();
-} finally{
-    lzVar4 = saved();
-}
-export const t4 = lzVar4;
-
-var lzVar5;
-try {
-    lzVar5 = passed();
-} catch (error) {
-    throw Error("catch");
-} finally{
-    lzVar5 = saved();
-}
-export const t5 = lzVar5;
-
-var lzVar6;
-try {
-    lzVar6 = passed();
-} catch (error) {
-    lzVar6 = failed();
-} finally{
-    then();
-}
-export const t6 = lzVar6;
-
-var lzVar7;
-try {
-    lzVar7 = passed();
-} catch (error) {
-    throw Error("catch");
+    throw new Error("catch");
 } finally{
     then();
 }
-export const t7 = lzVar7;
+export const t3 = lzVar3;
 
-var lzVar8;
-if (!cond) {
-    lzVar8 = 1;
-} else {
+var lzVar4;
+if (cond) {
     try {
-        lzVar8 = passed();
+        lzVar4 = passed();
     } catch (error) {
-        lzVar8 = failed();
+        lzVar4 = failed();
     }
+} else {
+    lzVar4 = 1;
 }
-export const t8 = lzVar8;
+export const t4 = lzVar4;
@@ -12,7 +12,6 @@ export const t0 = lz.block(() => {
 });
 
 // output
-var lzVar;
 for(let i = 0; i < 5; i++){
     if (condA) {
         break;
@@ -22,13 +21,16 @@ for(let i = 0; i < 5; i++){
     }
     i++;
 }
-lzVar = 1;
-export const t0 = lzVar;
+export const t0 = 1;
 
 // output.compressed
-var lzVar;
-for(let i = 0; i < 5 && !condA; i++){
-    !condB && i++;
+for(let i = 0; i < 5; i++){
+    if (condA) {
+        break;
+    }
+    if (condB) {
+        continue;
+    }
+    i++;
 }
-lzVar = 1;
-export const t0 = lzVar;
+export const t0 = 1;
@@ -39,88 +39,82 @@ export const t1 = block(() => {
 
 // output
 var lzVar;
-var lzVar1;
 if (cond) {
-    lzVar1 = 0;
+    lzVar = 0;
 } else {
-    lzVar1 = 1;
+    lzVar = 1;
 }
-const inner = lzVar1;
-lzVar = inner + 1;
-export const t0 = lzVar;
+const inner = lzVar;
+export const t0 = inner + 1;
 
-var lzVar2;
+var lzVar1;
 if (cond) {
-    var lzVar3;
+    var lzVar2;
     switch(val){
         case A:
             {
-                lzVar3 = 0;
+                lzVar2 = 0;
                 break;
             }
         case B:
             {
-                var lzVar4;
+                var lzVar3;
                 if (condInner) {
-                    lzVar4 = 1;
+                    lzVar3 = 1;
                 } else {
                     throw new Error("inner1");
                 }
-                const inner1 = lzVar4;
-                lzVar3 = inner1;
+                const inner1 = lzVar3;
+                lzVar2 = inner1;
                 break;
             }
         default:
             {
-                lzVar3 = 2;
+                lzVar2 = 2;
                 break;
             }
     }
-    const inner0 = lzVar3;
-    lzVar2 = inner0 + 1;
+    const inner0 = lzVar2;
+    lzVar1 = inner0 + 1;
 } else {
-    lzVar2 = 1;
+    lzVar1 = 1;
 }
-export const t1 = lzVar2;
+export const t1 = lzVar1;
 
 // output.compressed
-var lzVar;
-var lzVar1;
-lzVar1 = cond ? 0 : 1;
-const inner = lzVar1;
-lzVar = inner + 1;
-export const t0 = lzVar;
+const inner = cond ? 0 : 1;
+export const t0 = inner + 1;
 
-var lzVar2;
+var lzVar;
 if (cond) {
-    var lzVar3;
+    var lzVar1;
     switch(val){
         case A:
             {
-                lzVar3 = 0;
+                lzVar1 = 0;
                 break;
             }
         case B:
             {
-                var lzVar4;
+                var lzVar2;
                 if (condInner) {
-                    lzVar4 = 1;
+                    lzVar2 = 1;
                 } else {
-                    throw Error("inner1");
+                    throw new Error("inner1");
                 }
-                const inner1 = lzVar4;
-                lzVar3 = inner1;
+                const inner1 = lzVar2;
+                lzVar1 = inner1;
                 break;
             }
         default:
             {
-                lzVar3 = 2;
+                lzVar1 = 2;
                 break;
             }
     }
-    const inner0 = lzVar3;
-    lzVar2 = inner0 + 1;
+    const inner0 = lzVar1;
+    lzVar = inner0 + 1;
 } else {
-    lzVar2 = 1;
+    lzVar = 1;
 }
-export const t1 = lzVar2;
+export const t1 = lzVar;
@@ -0,0 +1,115 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+async function f0() {
+    const data = await block(async () => {
+        const res = await fetch(url);
+        if (!res.ok) { return null; }
+        return await res.json();
+    });
+    return data;
+}
+
+export const f1 = async () => {
+    let value = await lz.block(async function () {
+        try {
+            return await load();
+        } catch (error) {
+            return fallback;
+        }
+    });
+    const sync = () => block(() => {
+        return 1;
+    });
+    return [value, sync];
+};
+
+async function f2() {
+    const a = await block(() => {
+        return p;
+    });
+    return a;
+}
+
+export const t0 = await block(async () => {
+    const res = await fetch(url);
+    if (!res.ok) { return null; }
+    return res.json();
+});
+
+// output
+
+async function f0() {
+    var lzVar;
+    const res = await fetch(url);
+    if (!res.ok) {
+        lzVar = null;
+    } else {
+        lzVar = await res.json();
+    }
+    const data = await lzVar;
+    return data;
+}
+
+export const f1 = async ()=>{
+    var lzVar;
+    try {
+        lzVar = await load();
+    } catch (error) {
+        lzVar = fallback;
+    }
+    let value = await lzVar;
+    const sync = ()=>{
+        let lzVar = 1;
+        return lzVar;
+    };
+    return [
+        value,
+        sync
+    ];
+};
+
+async function f2() {
+    const a = await p;
+    return a;
+}
+
+var lzVar;
+const res = await fetch(url);
+if (!res.ok) {
+    lzVar = null;
+} else {
+    lzVar = res.json();
+}
+export const t0 = await lzVar;
+
+// output.compressed
+async function f0() {
+    const res = await fetch(url);
+    const data = await (!res.ok ? null : await res.json());
+    return data;
+}
+export const f1 = async ()=>{
+    var lzVar;
+    try {
+        lzVar = await load();
+    } catch (error) {
+        lzVar = fallback;
+    }
+    let value = await lzVar;
+    const sync = ()=>{
+        let lzVar = 1;
+        return lzVar;
+    };
+    return [
+        value,
+        sync
+    ];
+};
+async function f2() {
+    const a = await p;
+    return a;
+}
+const res = await fetch(url);
+export const t0 = await (!res.ok ? null : res.json());
@@ -0,0 +1,73 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+export const t0 = block(() => compute());
+
+export let t1 = lz.block(() => {
+    return compute();
+});
+
+export const t2 = block(() => {
+    log();
+    return compute();
+});
+
+export const t3 = block(() => block(() => ({ value: 1 })));
+
+export const t4 = block(() => {
+    if (cond) return 0;
+    return 1;
+});
+
+async function f0() {
+    const data = await block(async () => await load());
+    return data;
+}
+
+// output
+export const t0 = compute();
+
+export let t1 = compute();
+
+log();
+export const t2 = compute();
+
+let lzVar = {
+    value: 1
+};
+
+export const t3 = lzVar;
+
+var lzVar1;
+if (cond) {
+    lzVar1 = 0;
+} else {
+    lzVar1 = 1;
+}
+export const t4 = lzVar1;
+
+async function f0() {
+    const data = await load();
+    return data;
+}
+
+// output.compressed
+export const t0 = compute();
+
+export let t1 = compute();
+
+log();
+export const t2 = compute();
+
+let lzVar = {
+    value: 1
+};
+export const t3 = lzVar;
+
+export const t4 = cond ? 0 : 1;
+
+async function f0() {
+    const data = await load();
+    return data;
+}
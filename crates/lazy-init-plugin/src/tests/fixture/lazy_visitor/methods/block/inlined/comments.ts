@@ -0,0 +1,98 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+export const t0 = lz.block(() => {
+    // Leading comment.
+    const value = compute(); // Trailing comment.
+    /* eslint-disable-next-line */
+    log(value);
+    /* The value. */
+    return value.missing;
+});
+
+export const t1 = block(() => {
+    /* a */
+    if (cond) {
+        /* b */
+        return 0;
+    }
+    /* c */
+    return 1;
+});
+
+export function t2() {
+    // Returns the block.
+    return block(() => {
+        log();
+        return 0;
+    });
+}
+export const t3 = block(() => {
+    /* a */
+    switch (key()) {
+        case "a":
+            // b
+            return 0;
+        default:
+            // c
+            return 1;
+    }
+});
+
+// output
+// Leading comment.
+const value = compute(); // Trailing comment.
+/* eslint-disable-next-line */ log(value);
+export const t0 = /* The value. */ value.missing;
+
+var lzVar;
+/* a */ if (cond) {
+    /* b */ lzVar = 0;
+} else {
+    /* c */ lzVar = 1;
+}
+export const t1 = lzVar;
+export function t2() {
+    log();
+    // Returns the block.
+    let lzVar = 0;
+    return lzVar;
+}
+var lzVar1;
+/* a */ switch(key()){
+    case "a":
+        // b
+        lzVar1 = 0;
+        break;
+    default:
+        // c
+        lzVar1 = 1;
+        break;
+}
+export const t3 = lzVar1;
+
+// output.compressed
+// Leading comment.
+const value = compute(); // Trailing comment.
+/* eslint-disable-next-line */ log(value);
+export const t0 = /* The value. */ value.missing;
+export const t1 = /* a */ /* b */ /* c */ cond ? 0 : 1;
+export function t2() {
+    log();
+    // Returns the block.
+    let lzVar = 0;
+    return lzVar;
+}
+var lzVar;
+/* a */ switch(key()){
+    case "a":
+        // b
+        lzVar = 0;
+        break;
+    default:
+        // c
+        lzVar = 1;
+        break;
+}
+export const t3 = lzVar;
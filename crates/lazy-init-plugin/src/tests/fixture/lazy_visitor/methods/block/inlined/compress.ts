@@ -0,0 +1,69 @@
+// @ts-nocheck
+import { block } from "lazy-init";
+
+// input
+export const t0 = block(() => {
+    if (a) return 0;
+    else if (b) return 1;
+    else return 2;
+});
+
+export const t1 = block(() => {
+    switch (key) {
+        case "a": return 0;
+        case "b":
+        case "c": return 1;
+        default: return 2;
+    }
+});
+
+export let t2 = block(() => {
+    log();
+    if (a) {
+        return cond ? x : y;
+    }
+    return z;
+});
+
+// output
+var lzVar;
+if (a) {
+    lzVar = 0;
+} else {
+    if (b) {
+        lzVar = 1;
+    } else {
+        lzVar = 2;
+    }
+}
+export const t0 = lzVar;
+
+var lzVar1;
+switch(key){
+    case "a":
+        lzVar1 = 0;
+        break;
+    case "b":
+    case "c":
+        lzVar1 = 1;
+        break;
+    default:
+        lzVar1 = 2;
+        break;
+}
+export const t1 = lzVar1;
+
+export let t2;
+log();
+if (a) {
+    t2 = cond ? x : y;
+} else {
+    t2 = z;
+}
+
+// output.compressed
+export const t0 = a ? 0 : b ? 1 : 2;
+
+export const t1 = key === "a" ? 0 : key === "b" || key === "c" ? 1 : 2;
+log();
+export let t2 = a ? cond ? x : y : z;
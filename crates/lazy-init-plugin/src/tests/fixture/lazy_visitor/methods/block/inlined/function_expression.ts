@@ -0,0 +1,59 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+class A {
+    method() {
+        const t0 = block(function () {
+            if (this === undefined) { return new.target; }
+            return this.value;
+        });
+        const t1 = block(() => {
+            return this.value;
+        });
+        const t2 = lz.block(function () {
+            const getThis = () => this;
+            const obj = {
+                value: 1,
+                get self() { return this; },
+                method() { return this.value; },
+            };
+            return [getThis(), obj];
+        });
+        return [t0, t1, t2];
+    }
+}
+
+// output
+
+class A {
+    method() {
+        var lzVar;
+        if (void 0 === undefined) {
+            lzVar = void 0;
+        } else {
+            lzVar = (void 0).value;
+        }
+        const t0 = lzVar;
+        const t1 = this.value;
+        const getThis = ()=>void 0;
+        const obj = {
+            value: 1,
+            get self () {
+                return this;
+            },
+            method () {
+                return this.value;
+            }
+        };
+        const t2 = [
+            getThis(),
+            obj
+        ];
+        return [
+            t0,
+            t1,
+            t2
+        ];
+    }
+}
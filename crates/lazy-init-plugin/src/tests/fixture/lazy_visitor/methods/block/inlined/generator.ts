@@ -0,0 +1,103 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+function* saga() {
+    const next = yield* block(function* () {
+        const action = yield take("NEXT");
+        if (!action) { return null; }
+        yield* handle(action);
+        return action.payload;
+    });
+    const sync = block(() => {
+        const items = [];
+        for (const item of source) {
+            if (item) items.push(item);
+        }
+        return items;
+    });
+    const rest = yield* block(() => {
+        return source;
+    });
+    yield put(next, sync, rest);
+}
+
+export const effects = {
+    *run() {
+        let result = yield* lz.block(function* () {
+            try {
+                return yield call(api);
+            } catch (error) {
+                return yield put(error);
+            }
+        });
+        return result;
+    },
+};
+
+// output
+function* saga() {
+    var lzVar;
+    const action = yield take("NEXT");
+    if (!action) {
+        lzVar = null;
+    } else {
+        yield* handle(action);
+        lzVar = action.payload;
+    }
+    const next = lzVar;
+    const items = [];
+    for (const item of source){
+        if (item) {
+            items.push(item);
+        }
+    }
+    const sync = items;
+    const rest = yield* source;
+    yield put(next, sync, rest);
+}
+
+export const effects = {
+    *run () {
+        let result;
+        try {
+            result = yield call(api);
+        } catch (error) {
+            result = yield put(error);
+        }
+        return result;
+    }
+};
+
+// output.compressed
+function* saga() {
+    var lzVar;
+    const action = yield take("NEXT");
+    if (!action) {
+        lzVar = null;
+    } else {
+        yield* handle(action);
+        lzVar = action.payload;
+    }
+    const next = lzVar;
+    const items = [];
+    for (const item of source){
+        if (item) {
+            items.push(item);
+        }
+    }
+    const sync = items;
+    const rest = yield* source;
+    yield put(next, sync, rest);
+}
+export const effects = {
+    *run () {
+        let result;
+        try {
+            result = yield call(api);
+        } catch (error) {
+            result = yield put(error);
+        }
+        return result;
+    }
+};
@@ -0,0 +1,74 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+function f0() {
+    const x = 1;
+    function helper() { return x; }
+    const t0 = block(() => {
+        const x = 2;
+        function helper() { return x; }
+        class Cls {}
+        return { x, helper, Cls };
+    });
+    return [x, helper, t0];
+}
+
+function f1(items) {
+    const results = [];
+    for (const item of items) {
+        const t1 = block(() => {
+            if (item) { var found = item; }
+            for (var i = 0, n = 1; i < n; i++) {}
+            for (var key in item) {}
+            return found ?? key;
+        });
+        results.push(t1);
+    }
+    var found = 0;
+    return [results, found];
+}
+
+// output
+
+function f0() {
+    const x = 1;
+    function helper() {
+        return x;
+    }
+    const x1 = 2;
+    function helper1() {
+        return x1;
+    }
+    class Cls {
+    }
+    const t0 = {
+        x: x1,
+        helper: helper1,
+        Cls
+    };
+    return [
+        x,
+        helper,
+        t0
+    ];
+}
+
+function f1(items) {
+    const results = [];
+    for (const item of items){
+        let found, i, n, key;
+        if (item) {
+            found = item;
+        }
+        for(i = 0, n = 1; i < n; i++){}
+        for(key in item){}
+        const t1 = found ?? key;
+        results.push(t1);
+    }
+    var found = 0;
+    return [
+        results,
+        found
+    ];
+}
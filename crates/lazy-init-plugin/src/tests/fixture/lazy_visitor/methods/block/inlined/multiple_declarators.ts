@@ -0,0 +1,92 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+export const t0 = 0, t1 = block(() => {
+    if (cond) { return t0; }
+    return 1;
+}), t2 = t1 + 1;
+
+function f0() {
+    let a = 1, b = lz.block(() => {
+        if (cond) { return a; }
+        return 2;
+    });
+    return b;
+}
+
+function f1() {
+    const a = block(() => {
+        if (cond) { return 0; }
+        return 1;
+    }), b = a + 1, c = block(() => {
+        if (cond) { return b; }
+        return 2;
+    });
+    return c;
+}
+
+function f2() {
+    const a = () => block(() => {
+        if (cond) { return 0; }
+        return 1;
+    }), b = 1;
+    return [a, b];
+}
+
+// output
+export const t0 = 0;
+var lzVar;
+if (cond) {
+    lzVar = t0;
+} else {
+    lzVar = 1;
+}
+export const t1 = lzVar;
+export const t2 = t1 + 1;
+
+function f0() {
+    let a = 1;
+    let b;
+    if (cond) {
+        b = a;
+    } else {
+        b = 2;
+    }
+    return b;
+}
+
+function f1() {
+    var lzVar;
+    if (cond) {
+        lzVar = 0;
+    } else {
+        lzVar = 1;
+    }
+    const a = lzVar;
+    const b = a + 1;
+    var lzVar1;
+    if (cond) {
+        lzVar1 = b;
+    } else {
+        lzVar1 = 2;
+    }
+    const c = lzVar1;
+    return c;
+}
+
+function f2() {
+    const a = ()=>{
+        let lzVar;
+        if (cond) {
+            lzVar = 0;
+        } else {
+            lzVar = 1;
+        }
+        return lzVar;
+    }, b = 1;
+    return [
+        a,
+        b
+    ];
+}
@@ -69,22 +69,34 @@ export const t0 = lzVar;
 var lzVar;
 Block: switch(outer){
     case 0:
-        lzVar = "outer-0";
-        break;
+        {
+            lzVar = "outer-0";
+            break;
+        }
     case 1:
-        switch(inner){
-            case 0:
-                lzVar = "inner-0";
-                break Block;
-            case 1:
-                lzVar = "inner-1";
-                break Block;
-            default:
-                lzVar = "inner-default";
-                break Block;
+        {
+            switch(inner){
+                case 0:
+                    {
+                        lzVar = "inner-0";
+                        break Block;
+                    }
+                case 1:
+                    {
+                        lzVar = "inner-1";
+                        break Block;
+                    }
+                default:
+                    {
+                        lzVar = "inner-default";
+                        break Block;
+                    }
+            }
         }
     default:
-        lzVar = "outer-default";
-        break;
+        {
+            lzVar = "outer-default";
+            break;
+        }
 }
 export const t0 = lzVar;
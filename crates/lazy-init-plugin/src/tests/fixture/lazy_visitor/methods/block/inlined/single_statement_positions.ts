@@ -0,0 +1,103 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+function f0() {
+    if (a) var v0 = block(() => {
+        if (cond) { return 0; }
+        return 1;
+    });
+    else var v1 = lz.block(() => {
+        if (cond) { return 2; }
+        return 3;
+    });
+    return v0 ?? v1;
+}
+
+function f1() {
+    for (const item of items) var v = block(() => {
+        if (item) { return item; }
+        return 0;
+    });
+    while (cond) var w = block(() => {
+        return 1;
+    });
+    label: var x = block(() => {
+        return 2;
+    });
+    return v + w + x;
+}
+
+export const f2 = () => block(() => {
+    if (cond) { return 0; }
+    return 1;
+});
+
+function f3() {
+    if (a) return block(() => {
+        if (cond) { return 0; }
+        return 1;
+    });
+    return 2;
+}
+
+// output
+function f0() {
+    if (a) {
+        var v0;
+        if (cond) {
+            v0 = 0;
+        } else {
+            v0 = 1;
+        }
+    } else {
+        var v1;
+        if (cond) {
+            v1 = 2;
+        } else {
+            v1 = 3;
+        }
+    }
+    return v0 ?? v1;
+}
+
+function f1() {
+    for (const item of items){
+        var v;
+        if (item) {
+            v = item;
+        } else {
+            v = 0;
+        }
+    }
+    while(cond){
+        var w = 1;
+    }
+    label: {
+        var x = 2;
+    }
+    return v + w + x;
+}
+
+export const f2 = ()=>{
+    let lzVar;
+    if (cond) {
+        lzVar = 0;
+    } else {
+        lzVar = 1;
+    }
+    return lzVar;
+};
+
+function f3() {
+    if (a) {
+        let lzVar;
+        if (cond) {
+            lzVar = 0;
+        } else {
+            lzVar = 1;
+        }
+        return lzVar;
+    }
+    return 2;
+}
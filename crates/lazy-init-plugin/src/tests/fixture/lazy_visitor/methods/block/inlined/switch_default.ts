@@ -0,0 +1,76 @@
+// @ts-nocheck
+import { block } from "lazy-init";
+
+// input
+export const t0 = block(() => {
+    switch (val) {
+        default: return 2;
+        case A: return 0;
+        case B: return 1;
+    }
+});
+
+export const t1 = block(() => {
+    switch (val) {
+        case A: return 0;
+        default:
+        case B: return 1;
+        case C: return 2;
+    }
+});
+
+export const t2 = block(() => {
+    switch (val) {
+        default:
+            log(val);
+        case A: {
+            val += A;
+        }
+        case B: return 1;
+    }
+});
+
+// output
+var lzVar;
+switch(val){
+    default:
+        lzVar = 2;
+        break;
+    case A:
+        lzVar = 0;
+        break;
+    case B:
+        lzVar = 1;
+        break;
+}
+
+export const t0 = lzVar;
+var lzVar1;
+switch(val){
+    case A:
+        lzVar1 = 0;
+        break;
+    default:
+    case B:
+        lzVar1 = 1;
+        break;
+    case C:
+        lzVar1 = 2;
+        break;
+}
+
+export const t1 = lzVar1;
+var lzVar2;
+switch(val){
+    default:
+        log(val);
+    case A:
+        {
+            val += A;
+        }
+    case B:
+        lzVar2 = 1;
+        break;
+}
+
+export const t2 = lzVar2;
@@ -0,0 +1,159 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+export const g0 = block(() => {
+    if (a) return 1;
+    if (b) throw new Error("b");
+    throw new Error("c");
+});
+
+export const g1 = block(() => {
+    if (a) {
+        if (b) throw new Error("b");
+        throw new Error("a");
+    }
+    return 1;
+});
+
+export const g2 = block(() => {
+    try {
+        if (a) throw new Error("a");
+        throw new Error("b");
+    } catch (e) {
+        return 1;
+    }
+});
+
+export const t0 = block(() => {
+    if (a) return 1;
+    throw new Error("unreachable");
+});
+
+export const t1 = block(() => {
+    if (!a) throw new Error("guard");
+    if (!b) {
+        throw new Error("guard");
+    }
+    return 1;
+});
+
+export const t2 = block(() => {
+    switch (val) {
+        case A: return 0;
+        case B: throw new Error("B");
+        default: throw new Error("unknown");
+    }
+});
+
+export const t3 = block(() => {
+    try {
+        return load();
+    } catch (error) {
+        throw new Error("failed");
+    }
+});
+
+export const t4 = block(() => {
+    for (const item of items) {
+        if (item) { throw item; }
+    }
+    return 1;
+});
+
+export const t5 = block(() => {
+    if (a) {
+        return 1;
+    } else if (b) {
+        throw new Error("b");
+    }
+    throw new Error("c");
+});
+
+// output
+var lzVar;
+if (a) {
+    lzVar = 1;
+} else {
+    if (b) {
+        throw new Error("b");
+    }
+    throw new Error("c");
+}
+export const g0 = lzVar;
+
+if (a) {
+    if (b) {
+        throw new Error("b");
+    }
+    throw new Error("a");
+}
+export const g1 = 1;
+
+var lzVar1;
+try {
+    if (a) {
+        throw new Error("a");
+    }
+    throw new Error("b");
+} catch (e) {
+    lzVar1 = 1;
+}
+export const g2 = lzVar1;
+
+var lzVar2;
+if (a) {
+    lzVar2 = 1;
+} else {
+    throw new Error("unreachable");
+}
+export const t0 = lzVar2;
+
+if (!a) {
+    throw new Error("guard");
+}
+if (!b) {
+    throw new Error("guard");
+}
+export const t1 = 1;
+
+var lzVar3;
+switch(val){
+    case A:
+        lzVar3 = 0;
+        break;
+    case B:
+        throw new Error("B");
+    default:
+        throw new Error("unknown");
+}
+export const t2 = lzVar3;
+
+var lzVar4;
+try {
+    lzVar4 = load();
+} catch (error) {
+    throw new Error("failed");
+}
+export const t3 = lzVar4;
+
+for (const item of items){
+    if (item) {
+        throw item;
+    }
+}
+export const t4 = 1;
+
+var lzVar5;
+Block: {
+    if (a) {
+        lzVar5 = 1;
+        break Block;
+    } else {
+        if (b) {
+            throw new Error("b");
+        }
+    }
+    throw new Error("c");
+}
+export const t5 = lzVar5;
@@ -0,0 +1,74 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+export const t0 = lz.block(() => {
+    try {
+        return passed();
+    } finally {
+        cleanup();
+    }
+});
+
+export const t1 = block(() => {
+    try {
+        return passed();
+    } finally {
+        throw new Error("finally");
+    }
+});
+
+export const t2 = lz.block(() => {
+    try {
+        return passed();
+    } catch (error) {
+        return failed();
+    } finally {
+        throw new Error("finally");
+    }
+});
+
+export const t3 = block(() => {
+    let value = 0;
+    try {
+        return value;
+    } finally {
+        value = 1;
+    }
+});
+
+// output
+var lzVar;
+try {
+    lzVar = passed();
+} finally{
+    cleanup();
+}
+export const t0 = lzVar;
+
+var lzVar1;
+try {
+    lzVar1 = passed();
+} finally{
+    throw new Error("finally");
+}
+export const t1 = lzVar1;
+
+var lzVar2;
+try {
+    lzVar2 = passed();
+} catch (error) {
+    lzVar2 = failed();
+} finally{
+    throw new Error("finally");
+}
+export const t2 = lzVar2;
+
+var lzVar3;
+let value = 0;
+try {
+    lzVar3 = value;
+} finally{
+    value = 1;
+}
+export const t3 = lzVar3;
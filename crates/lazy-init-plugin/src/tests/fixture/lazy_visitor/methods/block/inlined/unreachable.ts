@@ -0,0 +1,93 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+export const t0 = lz.block(() => {
+    const value = compute();
+    return value;
+    log(value);
+    cleanup();
+});
+
+export const t1 = block(() => {
+    if (cond) {
+        return 0;
+    } else {
+        throw new Error("oops");
+    }
+    log();
+    return 1;
+});
+
+export const t2 = lz.block(() => {
+    if (cond) {
+        return 0;
+        log();
+    }
+    return helper();
+    function helper() {
+        return 1;
+    }
+});
+
+export const t3 = block(() => {
+    try {
+        return passed();
+        log();
+    } catch (error) {
+        return failed();
+    }
+    return 1;
+});
+
+export const t4 = lz.block(() => {
+    switch (key) {
+        case "a":
+            return 0;
+            log();
+        default:
+            return 1;
+    }
+});
+
+// output
+const value = compute();
+export const t0 = value;
+
+var lzVar;
+if (cond) {
+    lzVar = 0;
+} else {
+    throw new Error("oops");
+}
+export const t1 = lzVar;
+
+var lzVar1;
+if (cond) {
+    lzVar1 = 0;
+} else {
+    lzVar1 = helper();
+    function helper() {
+        return 1;
+    }
+}
+export const t2 = lzVar1;
+
+var lzVar2;
+try {
+    lzVar2 = passed();
+} catch (error) {
+    lzVar2 = failed();
+}
+export const t3 = lzVar2;
+
+var lzVar3;
+switch(key){
+    case "a":
+        lzVar3 = 0;
+        break;
+    default:
+        lzVar3 = 1;
+        break;
+}
+export const t4 = lzVar3;
@@ -78,11 +78,11 @@ export const t0 = lzVar;
 
 var lzVar1;
 Block: {
-    if (!cond) {
+    if (cond) {
+        fallthrough();
+    } else {
         lzVar1 = 1;
         break Block;
-    } else {
-        fallthrough();
     }
 }
 export const t1 = lzVar1;
@@ -53,7 +53,19 @@ export const t1 = lzVar1;
 
 // output.compressed
 var lzVar;
-lzVar = condA ? "A" : condB ? "B" : void fallthrough();
+Block: {
+    if (condA) {
+        lzVar = "A";
+        break Block;
+    } else {
+        if (condB) {
+            lzVar = "B";
+            break Block;
+        } else {
+            fallthrough();
+        }
+    }
+}
 export const t0 = lzVar;
 
 var lzVar1;
@@ -33,21 +33,20 @@ Block: {
     }
 }
 export const t0 = lzVar;
-var lzVar1;
+
 if (condA) {
     if (condB) {
         throw new Error("B");
     }
     fallthrough();
 }
-lzVar1 = "C";
-export const t1 = lzVar1;
+export const t1 = "C";
 
 // output.compressed
 var lzVar;
 Block: {
     if (condA) {
-        throw Error("A");
+        throw new Error("A");
     }
     if (condB) {
         lzVar = "B";
@@ -58,12 +57,10 @@ Block: {
 }
 export const t0 = lzVar;
 
-var lzVar1;
 if (condA) {
     if (condB) {
-        throw Error("B");
+        throw new Error("B");
     }
     fallthrough();
 }
-lzVar1 = "C";
-export const t1 = lzVar1;
+export const t1 = "C";
@@ -55,6 +55,7 @@ Block: {
     }
 }
 export const t0 = lzVar;
+
 var lzVar1;
 Block: {
     if (A) {
@@ -78,8 +79,43 @@ export const t1 = lzVar1;
 
 // output.compressed
 var lzVar;
-lzVar = A ? B ? void fallthrough() : 1 : C ? 0 : 1;
+Block: {
+    if (A) {
+        if (B) {
+            fallthrough();
+        } else {
+            lzVar = 1;
+            break Block;
+        }
+    } else {
+        if (C) {
+            lzVar = 0;
+            break Block;
+        } else {
+            lzVar = 1;
+            break Block;
+        }
+    }
+}
 export const t0 = lzVar;
+
 var lzVar1;
-lzVar1 = A ? B ? 0 : 1 : C ? 0 : void fallthrough();
+Block: {
+    if (A) {
+        if (B) {
+            lzVar1 = 0;
+            break Block;
+        } else {
+            lzVar1 = 1;
+            break Block;
+        }
+    } else {
+        if (C) {
+            lzVar1 = 0;
+            break Block;
+        } else {
+            fallthrough();
+        }
+    }
+}
 export const t1 = lzVar1;
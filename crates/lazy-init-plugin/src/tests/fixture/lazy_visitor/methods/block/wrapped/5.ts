@@ -203,16 +203,16 @@ export const t4 = lzVar4;
 
 var lzVar5;
 Block: {
-    if (!cond) {
-        lzVar5 = 1;
-        break Block;
-    } else {
+    if (cond) {
         try {
             maybe();
         } catch (error) {
             lzVar5 = failed();
             break Block;
         }
+    } else {
+        lzVar5 = 1;
+        break Block;
     }
 }
 export const t5 = lzVar5;
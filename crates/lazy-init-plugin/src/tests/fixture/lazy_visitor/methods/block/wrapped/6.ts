@@ -102,8 +102,10 @@ export const t3 = lzVar3;
 // output.compressed
 var lzVar;
 Block: {
-    for(let i = 0; i < 5 && !cond; i++){
-        ;
+    for(let i = 0; i < 5; i++){
+        if (cond) {
+            break;
+        }
         lzVar = 0;
         break Block;
     }
@@ -114,7 +116,10 @@ export const t0 = lzVar;
 
 var lzVar1;
 Block: {
-    for(let i = 0; i < 5 && !cond; i++){
+    label: for(let i = 0; i < 5; i++){
+        if (cond) {
+            break label;
+        }
         lzVar1 = 0;
         break Block;
     }
@@ -124,19 +129,32 @@ Block: {
 export const t1 = lzVar1;
 
 var lzVar2;
-switch(val){
-    case A:
-        lzVar2 = 0;
-        break;
-    case B:
-        lzVar2 = 1;
-        break;
-    default:
-        lzVar2 = 2;
-        break;
+Block: {
+    label: switch(val){
+        case A:
+            {
+                lzVar2 = 0;
+                break Block;
+            }
+        case B:
+            {
+                lzVar2 = 1;
+                break Block;
+            }
+        default:
+            {
+                lzVar2 = 2;
+                break Block;
+            }
+    }
 }
 export const t2 = lzVar2;
 
 var lzVar3;
-lzVar3 = 0;
+Block: {
+    label: {
+        lzVar3 = 0;
+        break Block;
+    }
+}
 export const t3 = lzVar3;
@@ -0,0 +1,114 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+export const t0 = lz.block(() => {
+    try {
+        throw new Error("try");
+    } finally {
+        return saved();
+    }
+});
+
+export const t1 = block(() => {
+    try {
+        return passed();
+    } finally {
+        return saved();
+    }
+});
+
+export const t2 = lz.block(() => {
+    try {
+        return passed();
+    } catch (error) {
+        return failed();
+    } finally {
+        return saved();
+    }
+});
+
+export const t3 = block(() => {
+    try {
+        return passed();
+    } catch (error) {
+        throw new Error("catch");
+    } finally {
+        return saved();
+    }
+});
+
+export const t4 = lz.block(() => {
+    try {
+        return passed();
+    } finally {
+        if (cond) return saved();
+    }
+});
+
+// output
+var lzVar;
+Block: {
+    try {
+        throw new Error("try");
+    } finally{
+        lzVar = saved();
+        break Block;
+    }
+}
+export const t0 = lzVar;
+
+var lzVar1;
+Block: {
+    try {
+        lzVar1 = passed();
+        break Block;
+    } finally{
+        lzVar1 = saved();
+        break Block;
+    }
+}
+export const t1 = lzVar1;
+
+var lzVar2;
+Block: {
+    try {
+        lzVar2 = passed();
+        break Block;
+    } catch (error) {
+        lzVar2 = failed();
+        break Block;
+    } finally{
+        lzVar2 = saved();
+        break Block;
+    }
+}
+export const t2 = lzVar2;
+
+var lzVar3;
+Block: {
+    try {
+        lzVar3 = passed();
+        break Block;
+    } catch (error) {
+        throw new Error("catch");
+    } finally{
+        lzVar3 = saved();
+        break Block;
+    }
+}
+export const t3 = lzVar3;
+
+var lzVar4;
+Block: {
+    try {
+        lzVar4 = passed();
+        break Block;
+    } finally{
+        if (cond) {
+            lzVar4 = saved();
+            break Block;
+        }
+    }
+}
+export const t4 = lzVar4;
@@ -0,0 +1,102 @@
+// @ts-nocheck
+import { block } from "lazy-init";
+
+// input
+export const t0 = block(() => {
+    if (!user) return "anonymous";
+    if (user.banned) return "banned";
+    if (!user.verified) return "unverified";
+    if (user.admin) return "admin";
+    if (user.moderator) return "moderator";
+    if (user.trial) return "trial";
+    if (user.expired) return "expired";
+    return "member";
+});
+
+export const t1 = block(() => {
+    if (a) return 0;
+    if (b) return 1;
+    if (c) return 2;
+    if (d) return 3;
+    if (e) return 4;
+    if (f) return 5;
+    if (g) return 6;
+    return 7;
+    log("unreachable");
+});
+
+// output
+var lzVar;
+Block: {
+    if (!user) {
+        lzVar = "anonymous";
+        break Block;
+    }
+    if (user.banned) {
+        lzVar = "banned";
+        break Block;
+    }
+    if (!user.verified) {
+        lzVar = "unverified";
+        break Block;
+    }
+    if (user.admin) {
+        lzVar = "admin";
+        break Block;
+    }
+    if (user.moderator) {
+        lzVar = "moderator";
+        break Block;
+    }
+    if (user.trial) {
+        lzVar = "trial";
+        break Block;
+    }
+    if (user.expired) {
+        lzVar = "expired";
+        break Block;
+    }
+    lzVar = "member";
+    break Block;
+}
+export const t0 = lzVar;
+
+var lzVar1;
+Block: {
+    if (a) {
+        lzVar1 = 0;
+        break Block;
+    }
+    if (b) {
+        lzVar1 = 1;
+        break Block;
+    }
+    if (c) {
+        lzVar1 = 2;
+        break Block;
+    }
+    if (d) {
+        lzVar1 = 3;
+        break Block;
+    }
+    if (e) {
+        lzVar1 = 4;
+        break Block;
+    }
+    if (f) {
+        lzVar1 = 5;
+        break Block;
+    }
+    if (g) {
+        lzVar1 = 6;
+        break Block;
+    }
+    lzVar1 = 7;
+    break Block;
+    log("unreachable");
+}
+export const t1 = lzVar1;
+
+// output.compressed
+export const t0 = !user ? "anonymous" : user.banned ? "banned" : !user.verified ? "unverified" : user.admin ? "admin" : user.moderator ? "moderator" : user.trial ? "trial" : user.expired ? "expired" : "member";
+export const t1 = a ? 0 : b ? 1 : c ? 2 : d ? 3 : e ? 4 : f ? 5 : g ? 6 : 7;
@@ -0,0 +1,80 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+export const t0 = /* lazy-init-force-wrapped */ lz.block(() => {
+    if (a) {
+        return 0;
+    }
+    return 1;
+});
+
+export const t1 = /** lazy-init-ignore */ block(() => {
+    if (a) {
+        return 0;
+    }
+    return 1;
+});
+
+export const t2 = block(() => {
+    if (a) {
+        return 0;
+    }
+    return 1;
+}, true);
+
+// output
+var lzVar;
+Block: {
+    if (a) {
+        lzVar = 0;
+        break Block;
+    }
+    lzVar = 1;
+    break Block;
+}
+export const t0 = /* lazy-init-force-wrapped */ lzVar;
+export const t1 = /** lazy-init-ignore */ block(()=>{
+    if (a) {
+        return 0;
+    }
+    return 1;
+});
+var lzVar1;
+Block: {
+    if (a) {
+        lzVar1 = 0;
+        break Block;
+    }
+    lzVar1 = 1;
+    break Block;
+}
+export const t2 = lzVar1;
+
+// output.compressed
+var lzVar;
+Block: {
+    if (a) {
+        lzVar = 0;
+        break Block;
+    }
+    lzVar = 1;
+    break Block;
+}
+export const t0 = /* lazy-init-force-wrapped */ lzVar;
+export const t1 = /** lazy-init-ignore */ block(()=>{
+    if (a) {
+        return 0;
+    }
+    return 1;
+});
+var lzVar1;
+Block: {
+    if (a) {
+        lzVar1 = 0;
+        break Block;
+    }
+    lzVar1 = 1;
+    break Block;
+}
+export const t2 = lzVar1;
@@ -0,0 +1,109 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+// input
+foo(block(() => {
+    return 1;
+}));
+
+x = lz.block(() => {
+    if (a) return 1;
+    else return 2;
+});
+
+let { a, b } = block(() => {
+    if (cond) return { a: 1, b: 2 };
+    return other;
+});
+
+export const t0 = block(async () => {
+    return await load();
+});
+
+export const t1 = lz.block(function () {
+    return arguments.length;
+});
+
+export const t2 = block(() => {
+    const inner = block(() => {
+        if (cond) return 1;
+        else return 2;
+    });
+    return [inner, block(() => {
+        return 3;
+    })];
+});
+
+for (let i = 0, j = block(() => { return 1; }); i < j; i++) {}
+
+async function f0() {
+    const a = block(async () => {
+        return await load();
+    });
+    return a;
+}
+
+function* g0() {
+    const a = block(function* () {
+        return yield load();
+    });
+    return a;
+}
+
+// output
+foo((()=>{
+    return 1;
+})());
+
+x = (()=>{
+    if (a) return 1;
+    else return 2;
+})();
+
+let { a, b } = (()=>{
+    if (cond) return {
+        a: 1,
+        b: 2
+    };
+    return other;
+})();
+
+export const t0 = (async ()=>{
+    return await load();
+})();
+
+export const t1 = function() {
+    return arguments.length;
+}();
+
+var lzVar;
+if (cond) {
+    lzVar = 1;
+} else {
+    lzVar = 2;
+}
+const inner = lzVar;
+export const t2 = [
+    inner,
+    (()=>{
+        return 3;
+    })()
+];
+
+for(let i = 0, j = (()=>{
+    return 1;
+})(); i < j; i++){}
+
+async function f0() {
+    const a = (async ()=>{
+        return await load();
+    })();
+    return a;
+}
+
+function* g0() {
+    const a = function*() {
+        return yield load();
+    }();
+    return a;
+}
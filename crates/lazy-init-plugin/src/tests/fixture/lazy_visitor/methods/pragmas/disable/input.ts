@@ -0,0 +1,10 @@
+/* lazy-init-disable */
+// @ts-nocheck
+import { lz } from "lazy-init";
+
+export const a = () => {
+   const lz_a0 = lz({ "a": 0 });
+   const lz_b0 = lz.block(() => {
+      return foo();
+   });
+};
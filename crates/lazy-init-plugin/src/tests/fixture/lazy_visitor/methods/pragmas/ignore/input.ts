@@ -0,0 +1,12 @@
+// @ts-nocheck
+import { lz } from "lazy-init";
+
+export const a = () => {
+   const lz_a0 = lz({ "a": 0 });
+   const lz_a1 = /* lazy-init-ignore */ lz({ "a": 1 });
+   const lz_a2 = [
+      // lazy-init-ignore: a new object is needed for each call.
+      lz({ "a": 2 }),
+   ];
+   const lz_a3 = /* lazy-init-ignore-all */ lz({ "a": 3 });
+};
@@ -0,0 +1,20 @@
+// @ts-nocheck
+import { lz } from "lazy-init";
+var lzVar, lzVar1;
+export const a = ()=>{
+    const lz_a0 = (lzVar ?? (lzVar = lz({
+        "a": 0
+    })));
+    const lz_a1 = /* lazy-init-ignore */ lz({
+        "a": 1
+    });
+    const lz_a2 = [
+        // lazy-init-ignore: a new object is needed for each call.
+        lz({
+            "a": 2
+        })
+    ];
+    const lz_a3 = /* lazy-init-ignore-all */ (lzVar1 ?? (lzVar1 = lz({
+        "a": 3
+    })));
+};
@@ -0,0 +1,34 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+var lzVar, lzVar1, lzVar2;
+export const a = ()=>(lzVar ?? (lzVar = lz({
+        a: 0
+    })));
+export const b = ()=>(lzVar1 ?? (lzVar1 = lz.fn(()=>0)));
+export const c = async ()=>(lzVar2 ?? (lzVar2 = await lz.async(fetchData, void 0, "uniquekey123")));
+export const d = ()=>{
+    var lzVar;
+    Block: {
+        if (x) {
+            lzVar = 0;
+            break Block;
+        }
+        lzVar = 1;
+        break Block;
+    }
+    const inlined = lzVar;
+    var lzVar1;
+    Block: {
+        do {
+            lzVar1 = 0;
+            break Block;
+        }while (x)
+        lzVar1 = 1;
+        break Block;
+    }
+    const wrapped = lzVar1;
+    return [
+        inlined,
+        wrapped
+    ];
+};
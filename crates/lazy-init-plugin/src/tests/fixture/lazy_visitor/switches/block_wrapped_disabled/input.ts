@@ -0,0 +1,38 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+
+export const a = () => lz({ a: 0 });
+export const b = () => lz.fn(() => 0);
+export const c = async () => lz.async(fetchData);
+
+export const d = () => {
+   const inlined = block(() => {
+      if (x) {
+         return 0;
+      }
+      return 1;
+   });
+   const wrapped = block(() => {
+      do {
+         return 0;
+      } while (x);
+      return 1;
+   });
+   return [inlined, wrapped];
+};
+
+export const e = () => {
+   const wrapped = block(() => {
+      const nested = block(() => {
+         if (x) {
+            return 0;
+         }
+         return 1;
+      });
+      do {
+         return nested;
+      } while (x);
+      return 1;
+   });
+   return wrapped;
+};
@@ -0,0 +1,43 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+var lzVar, lzVar1, lzVar2;
+export const a = ()=>(lzVar ?? (lzVar = lz({
+        a: 0
+    })));
+export const b = ()=>(lzVar1 ?? (lzVar1 = lz.fn(()=>0)));
+export const c = async ()=>(lzVar2 ?? (lzVar2 = await lz.async(fetchData, void 0, "uniquekey123")));
+export const d = ()=>{
+    var lzVar;
+    if (x) {
+        lzVar = 0;
+    } else {
+        lzVar = 1;
+    }
+    const inlined = lzVar;
+    const wrapped = block(()=>{
+        do {
+            return 0;
+        }while (x)
+        return 1;
+    });
+    return [
+        inlined,
+        wrapped
+    ];
+};
+export const e = ()=>{
+    const wrapped = block(()=>{
+        var lzVar;
+        if (x) {
+            lzVar = 0;
+        } else {
+            lzVar = 1;
+        }
+        const nested = lzVar;
+        do {
+            return nested;
+        }while (x)
+        return 1;
+    });
+    return wrapped;
+};
@@ -0,0 +1,28 @@
+// @ts-nocheck
+import { lz, block } from "lazy-init";
+var lzVar, lzVar1;
+export const a = ()=>(lzVar ?? (lzVar = lz({
+        a: 0
+    })));
+export const b = ()=>(lzVar1 ?? (lzVar1 = lz.fn(()=>0)));
+export const c = async ()=>lz.async(fetchData, void 0, "uniquekey123");
+export const e = async ()=>lz.async(fetchData, {
+        retries: 2
+    }, "uniquekey123");
+export const d = ()=>{
+    const inlined = x ? 0 : 1;
+    var lzVar;
+    Block: {
+        do {
+            lzVar = 0;
+            break Block;
+        }while (x)
+        lzVar = 1;
+        break Block;
+    }
+    const wrapped = lzVar;
+    return [
+        inlined,
+        wrapped
+    ];
+};
@@ -9,7 +9,7 @@ use swc_ecma_ast::*;
 use swc_ecma_parser::{Syntax, TsConfig};
 use swc_ecma_transforms_base::resolver;
 use swc_ecma_transforms_testing::{test_fixture, FixtureTestConfig, Tester};
-use swc_ecma_visit::as_folder;
+use swc_ecma_visit::{as_folder, FoldWith};
 use testing::fixture;
 
 use super::*;
@@ -22,7 +22,7 @@ fn lazy_block_test(input: PathBuf) {
 
    split_test_fixture(
       ts_syntax(),
-      &|_tr, is_compressed| {
+      &|tr, is_compressed| {
          let unresolved_mark = Mark::new();
          let top_level_mark = Mark::new();
 
@@ -34,6 +34,40 @@ fn lazy_block_test(input: PathBuf) {
                   ..PluginConfig::default()
                },
                visitor::ProgramMetadata {
+                  comments: Some(Box::new((*tr.comments).clone())),
+                  unresolved_mark,
+               },
+            ))
+         );
+      },
+      &input,
+   );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[fixture("src/tests/fixture/lazy_visitor/methods/block_fallback/*.ts")]
+fn lazy_block_fallback_test(input: PathBuf) {
+   use crate::{
+      configs::{BlockFallback, PluginConfig},
+      visitor,
+   };
+
+   split_test_fixture(
+      ts_syntax(),
+      &|tr, _| {
+         let unresolved_mark = Mark::new();
+         let top_level_mark = Mark::new();
+
+         return chain!(
+            resolver(unresolved_mark, top_level_mark, false),
+            as_folder(visitor::LazyVisitor::new(
+               PluginConfig {
+                  block_fallback: BlockFallback::Silent,
+                  compress_blocks: false,
+                  ..PluginConfig::default()
+               },
+               visitor::ProgramMetadata {
+                  comments: Some(Box::new((*tr.comments).clone())),
                   unresolved_mark,
                },
             ))
@@ -59,7 +93,7 @@ fn lazy_hoist_test(input: PathBuf) {
 
    test_fixture(
       ts_syntax(),
-      &|_tr| {
+      &|tr| {
          let unresolved_mark = Mark::new();
          let top_level_mark = Mark::new();
          return chain!(
@@ -70,6 +104,7 @@ fn lazy_hoist_test(input: PathBuf) {
                   ..PluginConfig::default()
                },
                visitor::ProgramMetadata {
+                  comments: Some(Box::new((*tr.comments).clone())),
                   unresolved_mark,
                },
             ))
@@ -81,12 +116,92 @@ fn lazy_hoist_test(input: PathBuf) {
    );
 }
 
+#[allow(clippy::needless_pass_by_value)]
+#[fixture("src/tests/fixture/lazy_visitor/switches/**/input.ts")]
+fn lazy_switches_test(input: PathBuf) {
+   use crate::{configs::parse_config, visitor};
+
+   let dir = input.parent().expect("Failed to find test fixture.");
+   let config = parse_config(
+      &read_to_string(dir.join("config.json"))
+         .expect("Failed to read test config."),
+   )
+   .expect("Failed to parse test config.");
+
+   test_fixture(
+      ts_syntax(),
+      &|tr| {
+         let unresolved_mark = Mark::new();
+         let top_level_mark = Mark::new();
+         return chain!(
+            resolver(unresolved_mark, top_level_mark, false),
+            as_folder(visitor::LazyVisitor::new(
+               config.clone(),
+               visitor::ProgramMetadata {
+                  comments: Some(Box::new((*tr.comments).clone())),
+                  unresolved_mark,
+               },
+            ))
+         );
+      },
+      &input,
+      &dir.join("output.ts"),
+      FixtureTestConfig::default(),
+   );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[fixture("src/tests/fixture/block_compressor/*.ts")]
+fn block_compressor_test(input: PathBuf) {
+   use crate::block::compress;
+   use swc_common::DUMMY_SP;
+   use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+   /// Compresses the body of each function, where `lzVar` is the temp
+   /// variable.
+   struct Compressor {
+      unresolved_mark: Mark,
+      comments: swc_common::comments::SingleThreadedComments,
+   }
+
+   impl VisitMut for Compressor {
+      fn visit_mut_function(&mut self, function: &mut Function) {
+         function.visit_mut_children_with(self);
+
+         let body = unwrap_or!(function.body.as_mut(), return);
+         let temp_var = Ident::new(
+            "lzVar".into(),
+            DUMMY_SP.apply_mark(self.unresolved_mark),
+         );
+         compress(&mut body.stmts, &temp_var, Some(&self.comments));
+      }
+   }
+
+   split_test_fixture(
+      ts_syntax(),
+      &|tr, _| {
+         let unresolved_mark = Mark::new();
+         let top_level_mark = Mark::new();
+
+         return chain!(
+            resolver(unresolved_mark, top_level_mark, false),
+            as_folder(Compressor {
+               unresolved_mark,
+               comments: (*tr.comments).clone(),
+            })
+         );
+      },
+      &input,
+   );
+}
+
 #[fixture("src/tests/fixture/block_analyzer/*.ts")]
 fn block_analyzer_test(input: PathBuf) {
    use crate::block::{analyze, TransformType};
 
    run_test(TestInput::File(input), |filepath, mut module| {
       let always_returns = filepath.contains("always_returns");
+      let unreachable = filepath.contains("unreachable");
 
       for stmt in module.body.drain(..) {
          if let ModuleItem::Stmt(Stmt::Decl(Decl::Fn(mut decl))) = stmt {
@@ -95,14 +210,22 @@ fn block_analyzer_test(input: PathBuf) {
             let mut block_stmt = Stmt::Block(body);
             let analyzed = analyze(&mut block_stmt);
 
-            let (transform_type, final_ctx) = match analyzed {
-               Ok(ok) => (ok.transform_type, ok.final_ctx),
+            let (transform_type, final_ctx, wrapped_reason) = match analyzed {
+               Ok(ok) => {
+                  if unreachable && ok.unreachable.is_empty() {
+                     return Result::Err((
+                        "Block has no unreachable statements!".to_owned(),
+                        Some(block_stmt.span()),
+                     ));
+                  }
+                  (ok.transform_type, ok.final_ctx, ok.wrapped_reason)
+               }
                Err(err) => {
                   return Result::Err((err.msg, err.span));
                }
             };
 
-            if always_returns {
+            if always_returns || unreachable {
                if transform_type != TransformType::Inline {
                   return Result::Err((
                      format!(
@@ -123,6 +246,14 @@ fn block_analyzer_test(input: PathBuf) {
                   Some(block_stmt.span()),
                ));
             };
+            if wrapped_reason.map_or(true, |r| return r.span.is_dummy()) {
+               return Result::Err((
+                  format!(
+                     "Block has no wrapped reason! Result:\n{final_ctx:#?}"
+                  ),
+                  Some(block_stmt.span()),
+               ));
+            }
          }
       }
 
@@ -130,6 +261,430 @@ fn block_analyzer_test(input: PathBuf) {
    });
 }
 
+#[test]
+fn diagnostics_test() {
+   use crate::{
+      configs::{BlockConfig, BlockFallback, DiagnosticLevel, PluginConfig},
+      diagnostics::DiagnosticCode,
+   };
+   use swc_common::errors::Level;
+
+   let with_fallback = |block_fallback| {
+      return PluginConfig {
+         block_fallback,
+         ..PluginConfig::default()
+      };
+   };
+   let with_level = |code, level| {
+      return PluginConfig {
+         diagnostics: [(code, level)].into_iter().collect(),
+         ..PluginConfig::default()
+      };
+   };
+
+   for (config, src, expected) in [
+      (
+         PluginConfig::default(),
+         "const a = block(() => { log(); });",
+         vec![(Level::Error, "LZ0001")],
+      ),
+      (
+         PluginConfig::default(),
+         "const a = block(() => {});",
+         vec![(Level::Error, "LZ0005")],
+      ),
+      (
+         PluginConfig::default(),
+         "foo(block(() => { return 0; }));",
+         vec![(Level::Error, "LZ0009")],
+      ),
+      (
+         with_fallback(BlockFallback::Warn),
+         "foo(block(() => { return 0; }));",
+         vec![(Level::Warning, "LZ0009")],
+      ),
+      (
+         with_fallback(BlockFallback::Silent),
+         "foo(block(() => { return 0; }));",
+         vec![],
+      ),
+      (
+         PluginConfig::default(),
+         "const a = block(() => { return 0; log(); });",
+         vec![(Level::Warning, "LZ0012")],
+      ),
+      (
+         with_level(
+            DiagnosticCode::UnreachableCode,
+            DiagnosticLevel::Error,
+         ),
+         "const a = block(() => { return 0; log(); });",
+         vec![(Level::Error, "LZ0012")],
+      ),
+      (
+         with_level(DiagnosticCode::BlockEmpty, DiagnosticLevel::Off),
+         "const a = block(() => {});",
+         vec![],
+      ),
+      (
+         PluginConfig::default(),
+         "async function f() { const a = block(async () => { return 0; }); }",
+         vec![(Level::Error, "LZ0018")],
+      ),
+      (
+         PluginConfig::default(),
+         "function* f() { const a = block(function* () { return 0; }); }",
+         vec![(Level::Error, "LZ0019")],
+      ),
+      (
+         PluginConfig::default(),
+         "const a = (x) => lz({ x });",
+         vec![(Level::Warning, "LZ0013")],
+      ),
+      (
+         PluginConfig::default(),
+         "const a = (x) => lz.fn((y) => ({ x, z: lz({ y }) }));",
+         vec![
+            (Level::Warning, "LZ0013"),
+            (Level::Warning, "LZ0013"),
+         ],
+      ),
+      (
+         PluginConfig::default(),
+         "const x = 1; const a = () => lz.fn((y) => ({ x, y, [x]: Math }));",
+         vec![],
+      ),
+      (
+         PluginConfig::default(),
+         "const a = lz(`abc`);",
+         vec![(Level::Warning, "LZ0014")],
+      ),
+      (
+         PluginConfig {
+            report_wrapped: true,
+            ..PluginConfig::default()
+         },
+         "const a = block(() => { do { return 1; } while (x); return 0; });",
+         vec![(Level::Warning, "LZ0015")],
+      ),
+      (
+         PluginConfig {
+            report_wrapped: true,
+            ..PluginConfig::default()
+         },
+         "const a = block(() => { if (x) { return 1; } log(); });",
+         vec![(Level::Warning, "LZ0015")],
+      ),
+      (
+         PluginConfig::default(),
+         "const a = block(() => { if (x) { return 1; } log(); });",
+         vec![],
+      ),
+      (
+         PluginConfig::default(),
+         "const a = block(() => { return 0; }, true);",
+         vec![(Level::Warning, "LZ0016")],
+      ),
+      (
+         PluginConfig::default(),
+         "const a = block(() => {}, true);",
+         vec![
+            (Level::Warning, "LZ0016"),
+            (Level::Error, "LZ0005"),
+         ],
+      ),
+      (
+         PluginConfig::default(),
+         "const a = /* lazy-init-ignore */ block(() => {});",
+         vec![],
+      ),
+      (
+         with_level(
+            DiagnosticCode::HoistPrimitive,
+            DiagnosticLevel::Off,
+         ),
+         "const a = lz(-1);",
+         vec![],
+      ),
+      // When the outer block falls back or is left as a runtime call, the
+      // diagnostics of nested blocks are reported for the restored call.
+      (
+         PluginConfig::default(),
+         "const a = block(() => {
+            const b = block(() => { return 0; log(); });
+            log(b);
+         });",
+         vec![
+            (Level::Error, "LZ0001"),
+            (Level::Warning, "LZ0012"),
+         ],
+      ),
+      (
+         PluginConfig {
+            block: BlockConfig {
+               wrapped: false,
+               ..BlockConfig::default()
+            },
+            ..PluginConfig::default()
+         },
+         "const a = block(() => {
+            const b = block(() => { return 0; log(); });
+            do { return b; } while (x);
+            return 0;
+         });",
+         vec![(Level::Warning, "LZ0012")],
+      ),
+   ] {
+      let src = format!("import {{ lz, block }} from \"lazy-init\";\n{src}");
+      let actual = collect_diagnostics(config, &src);
+      let actual: Vec<_> = actual
+         .iter()
+         .map(|(level, code)| return (*level, code.as_str()))
+         .collect();
+
+      assert_eq!(actual, expected, "Source:\n{src}");
+   }
+}
+
+#[test]
+fn spans_test() {
+   use crate::configs::{BlockTransform, PluginConfig};
+   use swc_common::errors::SourceMapper;
+   use swc_ecma_visit::{Visit, VisitWith};
+
+   /// Collects the source code that the generated nodes map to.
+   struct Collector<'a> {
+      cm: &'a swc_common::SourceMap,
+      found: Vec<(&'static str, String)>,
+   }
+
+   impl Collector<'_> {
+      fn add(&mut self, kind: &'static str, span: Span) {
+         let snippet = self
+            .cm
+            .span_to_snippet(span)
+            .unwrap_or_else(|_| return "<none>".to_owned());
+         let first_line = snippet.lines().next().unwrap_or_default();
+         self.found.push((kind, first_line.trim().to_owned()));
+      }
+   }
+
+   fn is_lazy_var(expr: &Expr) -> bool {
+      return expr
+         .as_ident()
+         .map_or(false, |i| return i.sym.starts_with("lzVar"));
+   }
+
+   impl Visit for Collector<'_> {
+      fn visit_stmt(&mut self, stmt: &Stmt) {
+         match stmt {
+            Stmt::Break(b) => self.add("break", b.span),
+            Stmt::Labeled(l) => self.add("label", l.span),
+            _ => {}
+         }
+         stmt.visit_children_with(self);
+      }
+
+      fn visit_assign_expr(&mut self, n: &AssignExpr) {
+         if n.left.as_expr().map_or(false, |e| return is_lazy_var(e)) {
+            self.add("assign", n.span);
+         }
+         n.visit_children_with(self);
+      }
+
+      fn visit_bin_expr(&mut self, n: &BinExpr) {
+         if is_lazy_var(&n.left) {
+            self.add("get", n.span);
+         }
+         n.visit_children_with(self);
+      }
+
+      fn visit_var_declarator(&mut self, n: &VarDeclarator) {
+         if let Some(init) = n.init.as_deref().filter(|e| return is_lazy_var(e))
+         {
+            self.add("init", init.span());
+         }
+         n.visit_children_with(self);
+      }
+   }
+
+   let src = r#"import { lz, block } from "lazy-init";
+function a(key) {
+   const value = block(() => {
+      switch (key) {
+         case "a":
+            return 1;
+         default:
+            return;
+      }
+   });
+   return value;
+}
+const b = lz([]);
+"#;
+
+   for (block_transform, mut expected) in [
+      (BlockTransform::Inline, vec![]),
+      // The label has the span of the body of the function.
+      (BlockTransform::Wrapped, vec![("label", "{")]),
+   ] {
+      expected.extend([
+         ("assign", "return 1;"),
+         ("break", "return 1;"),
+         ("assign", "return;"),
+         ("break", "return;"),
+         ("init", "block(() => {"),
+         ("get", "lz([])"),
+         ("assign", "lz([])"),
+      ]);
+      let (cm, module, _) = transform_source(
+         PluginConfig {
+            block_transform,
+            compress_blocks: false,
+            ..PluginConfig::default()
+         },
+         src,
+      );
+      let mut v = Collector {
+         cm: &cm,
+         found: Vec::new(),
+      };
+      module.visit_with(&mut v);
+
+      let expected: Vec<(&str, String)> = expected
+         .into_iter()
+         .map(|(kind, snippet)| return (kind, snippet.to_owned()))
+         .collect();
+      assert_eq!(v.found, expected, "{block_transform:?}");
+   }
+}
+
+#[test]
+fn script_test() {
+   use crate::configs::PluginConfig;
+   use swc_common::{sync::Lrc, FileName, Globals, SourceMap, GLOBALS};
+   use swc_ecma_parser::parse_file_as_script;
+
+   // Inlining a function expression replaces `this` with `undefined`, which
+   // is only correct in strict code. Modules are always strict, and scripts,
+   // which may be sloppy, are left as is.
+   let src = r#"var lazy = require("lazy-init");
+var a = lazy.block(function () {
+   return this;
+});
+"#;
+   let cm: Lrc<SourceMap> = Lrc::default();
+
+   GLOBALS.set(&Globals::new(), || {
+      let fm = cm.new_source_file(FileName::Anon, src.to_owned());
+      let script = parse_file_as_script(
+         &fm,
+         ts_syntax(),
+         EsVersion::latest(),
+         None,
+         &mut vec![],
+      )
+      .expect("Failed to parse source.");
+
+      let program = Program::Script(script.clone());
+      let program =
+         program.fold_with(&mut as_folder(visitor::LazyVisitor::new(
+            PluginConfig::default(),
+            visitor::ProgramMetadata {
+               comments: None,
+               unresolved_mark: Mark::new(),
+            },
+         )));
+
+      assert_eq!(program, Program::Script(script));
+   });
+}
+
+/// Transforms the source and returns the level and code of each diagnostic
+/// that was reported, including warnings.
+fn collect_diagnostics(
+   config: crate::configs::PluginConfig,
+   src: &str,
+) -> Vec<(swc_common::errors::Level, String)> {
+   return transform_source(config, src).2;
+}
+
+/// Transforms the source and returns the source map, the transformed module
+/// and the level and code of each diagnostic that was reported.
+fn transform_source(
+   config: crate::configs::PluginConfig,
+   src: &str,
+) -> (
+   swc_common::sync::Lrc<swc_common::SourceMap>,
+   Module,
+   Vec<(swc_common::errors::Level, String)>,
+) {
+   use std::sync::{Arc, Mutex};
+   use swc_common::{
+      comments::SingleThreadedComments,
+      errors::{DiagnosticBuilder, DiagnosticId, Emitter, Handler, HANDLER},
+      sync::Lrc,
+      FileName, Globals, SourceMap, GLOBALS,
+   };
+   use swc_ecma_parser::parse_file_as_module;
+
+   #[derive(Clone, Default)]
+   struct Collector(Arc<Mutex<Vec<(swc_common::errors::Level, String)>>>);
+
+   impl Emitter for Collector {
+      fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+         let code = match &db.code {
+            Some(DiagnosticId::Error(code) | DiagnosticId::Lint(code)) => {
+               code.clone()
+            }
+            None => String::new(),
+         };
+         self
+            .0
+            .lock()
+            .expect("Failed to lock.")
+            .push((db.level, code));
+      }
+   }
+
+   let cm: Lrc<SourceMap> = Lrc::default();
+   let comments = SingleThreadedComments::default();
+   let collector = Collector::default();
+   let handler =
+      Handler::with_emitter(true, false, Box::new(collector.clone()));
+
+   let module = GLOBALS.set(&Globals::new(), || {
+      return HANDLER.set(&handler, || {
+         let fm = cm.new_source_file(FileName::Anon, src.to_owned());
+         let module = parse_file_as_module(
+            &fm,
+            ts_syntax(),
+            EsVersion::latest(),
+            Some(&comments),
+            &mut vec![],
+         )
+         .expect("Failed to parse source.");
+
+         let unresolved_mark = Mark::new();
+         let top_level_mark = Mark::new();
+
+         return module.fold_with(&mut chain!(
+            resolver(unresolved_mark, top_level_mark, false),
+            as_folder(visitor::LazyVisitor::new(
+               config,
+               visitor::ProgramMetadata {
+                  comments: Some(Box::new(comments.clone())),
+                  unresolved_mark,
+               },
+            ))
+         ));
+      });
+   });
+   let diagnostics = collector.0.lock().expect("Failed to lock.").clone();
+
+   return (cm, module, diagnostics);
+}
+
 fn ts_syntax() -> Syntax {
    return Syntax::Typescript(TsConfig {
       tsx: false,
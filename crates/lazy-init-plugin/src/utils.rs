@@ -1,7 +1,8 @@
 use super::*;
-use swc_common::DUMMY_SP;
+use swc_common::{comments::Comments, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_utils::{private_ident, ExprFactory};
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
 #[derive(Debug, Clone)]
 pub struct LazyVar {
@@ -46,6 +47,15 @@ impl LazyVar {
    pub fn get_ident(&self) -> Ident {
       return self.ident.clone();
    }
+
+   /// Returns a reference to the variable positioned at `span`, so the
+   /// generated code maps to the source it replaces.
+   pub fn ident_at(&self, span: Span) -> Ident {
+      return Ident::new(
+         self.ident.sym.clone(),
+         span.with_ctxt(self.ident.span.ctxt),
+      );
+   }
 }
 
 #[derive(Debug, Clone)]
@@ -141,6 +151,59 @@ pub fn is_breakable_stmt(stmt: &Stmt) -> bool {
    return matches!(stmt, Stmt::Switch(_) | Stmt::Labeled(_));
 }
 
+/// Moves the comments of the statements within `removed` to `to`, so they are
+/// kept when the statements are replaced by a node with a different span.
+/// Both leading and trailing comments become leading comments of `to`, in the
+/// order they appear in, since the end of `to` is often within an expression.
+///
+/// Statements nested within expressions, e.g. in the body of an arrow
+/// function, are not removed and keep their comments.
+pub fn relocate_comments<N: VisitWith<StmtSpans>>(
+   comments: Option<&dyn Comments>,
+   removed: &N,
+   to: Span,
+) {
+   let comments = unwrap_or!(comments, return);
+   bail_if!(to.is_dummy());
+
+   let mut v = StmtSpans::default();
+   removed.visit_with(&mut v);
+
+   for span in v.spans {
+      if span.is_dummy() {
+         continue;
+      }
+      if span.lo != to.lo {
+         comments.move_leading(span.lo, to.lo);
+      }
+      if let Some(trailing) = comments.take_trailing(span.hi) {
+         comments.add_leading_comments(to.lo, trailing);
+      }
+   }
+}
+
+/// Collects the spans of statements, see [`relocate_comments`].
+#[derive(Default)]
+pub struct StmtSpans {
+   spans: Vec<Span>,
+}
+
+impl Visit for StmtSpans {
+   noop_visit_type!();
+
+   fn visit_expr(&mut self, _: &Expr) {}
+
+   fn visit_stmt(&mut self, stmt: &Stmt) {
+      self.spans.push(stmt.span());
+      stmt.visit_children_with(self);
+   }
+
+   fn visit_switch_case(&mut self, case: &SwitchCase) {
+      self.spans.push(case.span);
+      case.visit_children_with(self);
+   }
+}
+
 pub fn join_str(a: impl Into<String>, b: impl Into<String>) -> String {
    let mut a: String = a.into();
    a.push_str(&b.into());
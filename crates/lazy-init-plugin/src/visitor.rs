@@ -1,16 +1,19 @@
+use core::fmt::Debug;
 use swc_atoms::JsWord;
-use swc_common::Mark;
+use swc_common::{comments::Comments, Mark};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 use tracing::debug;
 
 use super::*;
 use block::LazyBlockVisitor;
-use configs::PluginConfig;
+use configs::{DiagnosticLevel, PluginConfig};
+use diagnostics::{Diagnostic, Severity};
 use hoist::LazyHoistVisitor;
 use lazy_init_pkg::{
    to_exported_fn, ExportedFn, LzMethodKind, PKG_NAME_JSWORD,
 };
+use pragmas::Pragma;
 
 #[derive(Debug)]
 pub struct ImportedFn {
@@ -26,9 +29,10 @@ pub struct LazyVisitor {
    pub metadata: ProgramMetadata,
 }
 
-#[derive(Debug)]
 pub struct ProgramMetadata {
-   // pub comments: Option<PluginCommentsProxy>,
+   /// Used to move the comments of statements that are removed when a block
+   /// is transformed, so they are not dropped.
+   pub comments: Option<Box<dyn Comments>>,
    // pub source_map: PluginSourceMapProxy,
    pub unresolved_mark: Mark,
 }
@@ -36,11 +40,22 @@ pub struct ProgramMetadata {
 impl Default for ProgramMetadata {
    fn default() -> Self {
       return ProgramMetadata {
+         comments: None,
          unresolved_mark: Mark::new(),
       };
    }
 }
 
+impl Debug for ProgramMetadata {
+   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+      return f
+         .debug_struct("ProgramMetadata")
+         .field("comments", &self.comments.is_some())
+         .field("unresolved_mark", &self.unresolved_mark)
+         .finish();
+   }
+}
+
 // impl ProgramMetadata {
 //    pub fn get_source_file(&mut self) -> Arc<swc_common::SourceFile> {
 //       return self.source_map.source_file.wait().clone();
@@ -75,6 +90,27 @@ impl LazyVisitor {
       };
    }
 
+   pub fn comments(&self) -> Option<&dyn Comments> {
+      return self.metadata.comments.as_deref();
+   }
+
+   /// Whether the pragma is one of the leading comments of the node at `pos`.
+   pub fn has_pragma(&self, pos: swc_common::BytePos, pragma: Pragma) -> bool {
+      return pragmas::has_pragma(self.comments(), pos, pragma);
+   }
+
+   /// Reports a diagnostic, unless it is turned off by the `diagnostics`
+   /// option, which can also change its severity.
+   pub fn report(&self, diagnostic: Diagnostic) {
+      let severity = match self.config.diagnostics.get(&diagnostic.code) {
+         Some(DiagnosticLevel::Off) => return,
+         Some(DiagnosticLevel::Warn) => Severity::Warning,
+         Some(DiagnosticLevel::Error) => Severity::Error,
+         None => diagnostic.severity,
+      };
+      diagnostic.with_severity(severity).emit();
+   }
+
    // Called by the `LazyBlockVisitor` and `LazyHoistVisitor`.
    pub fn find_lazy_method(
       &self,
@@ -82,6 +118,11 @@ impl LazyVisitor {
    ) -> Option<LzMethodFound> {
       // All methods require arguments.
       bail_if!(call_expr.args.is_empty(), None);
+      // `/* lazy-init-ignore */ lz(...)` is left as a runtime call.
+      bail_if!(
+         self.has_pragma(call_expr.span.lo, Pragma::Ignore),
+         None
+      );
 
       let callee_expr = call_expr.callee.as_expr()?;
       let (
@@ -131,6 +172,14 @@ impl LazyVisitor {
 impl VisitMut for LazyVisitor {
    noop_visit_mut_type!();
 
+   fn visit_mut_module(&mut self, module: &mut Module) {
+      // The file is opted out using `/* lazy-init-disable */` or
+      // `"use no lazy"`.
+      bail_if!(pragmas::is_disabled(self.comments(), module));
+
+      module.visit_mut_children_with(self);
+   }
+
    // Visitor entry point.
    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
       items.visit_mut_children_with(self);
@@ -208,7 +257,8 @@ impl VisitMut for LazyVisitor {
    }
 
    fn visit_mut_script(&mut self, _: &mut Script) {
-      // This plugin only supports modules since it relies on imports.
+      // This plugin only supports modules since it relies on imports. The
+      // transforms may also assume strict code, which modules always are.
       return;
    }
 }
@@ -3,13 +3,16 @@
  * function call.
  *
  * @param body A function with no arguments and a body that is a block statement that returns/throws.
- * @param forceWrapped Pass any value as the second argument to force the block to be wrapped.
+ * @param forceWrapped **Deprecated**, place a `lazy-init-force-wrapped` comment before the call instead. Pass any value as the second argument to force the block to be wrapped.
  * @returns The value returned by `body`.
  *
  * @remarks
  * All code paths within the block must end with a `return` statement, otherwise
  * the block will be inlined by wrapping it within a `labeled` statement.
  *
+ * To always wrap the block, place a `lazy-init-force-wrapped` comment before
+ * the call, e.g. `const a = /* lazy-init-force-wrapped *\/ block(...)`.
+ *
  * A `block(...)` call must always be on the RHS of a single variable
  * declaration, see `Proper Usage` below.
  *
@@ -66,6 +69,10 @@
  */
 export function block<R>(
    body: () => R,
+   /**
+    * @deprecated Place a `lazy-init-force-wrapped` comment before the call
+    * instead.
+    */
    // eslint-disable-next-line @typescript-eslint/no-unused-vars
    forceWrapped?: any
 ): R {